@@ -115,6 +115,14 @@ pub trait ContainerBuilder: Default + 'static {
     /// be called repeatedly until it returns `None`.
     #[must_use]
     fn finish(&mut self) -> Option<&mut Self::Container>;
+    /// Allocates a builder with the same configuration as this one, but holding no data.
+    ///
+    /// This allows a builder to be configured once, and the configuration replicated, for example
+    /// to the outputs of nested operators. The default implementation returns `Default::default()`,
+    /// which is correct for builders without configuration.
+    fn clone_empty(&self) -> Self {
+        Default::default()
+    }
 }
 
 /// A default container builder that uses length and preferred capacity to chunk data.
@@ -131,30 +139,20 @@ pub struct CapacityContainerBuilder<C>{
     empty: Option<C>,
     /// Completed containers pending to be sent.
     pending: VecDeque<C>,
+    /// The number of records at which to ship a container, if not the preferred capacity.
+    capacity: Option<usize>,
 }
 
 impl<T, C: SizableContainer + PushInto<T>> PushInto<T> for CapacityContainerBuilder<C> {
     #[inline]
     fn push_into(&mut self, item: T) {
-        if self.current.capacity() == 0 {
-            self.current = self.empty.take().unwrap_or_default();
-            // Discard any non-uniform capacity container.
-            if self.current.capacity() != C::preferred_capacity() {
-                self.current = C::default();
-            }
-            // Protect against non-emptied containers.
-            self.current.clear();
-        }
-        // Ensure capacity
-        if self.current.capacity() < C::preferred_capacity() {
-            self.current.reserve(C::preferred_capacity() - self.current.len());
-        }
+        self.prepare();
 
         // Push item
         self.current.push(item);
 
         // Maybe flush
-        if self.current.len() == self.current.capacity() {
+        if self.is_full() {
             self.pending.push_back(std::mem::take(&mut self.current));
         }
     }
@@ -163,6 +161,15 @@ impl<T, C: SizableContainer + PushInto<T>> PushInto<T> for CapacityContainerBuil
 impl<C: Container> ContainerBuilder for CapacityContainerBuilder<C> {
     type Container = C;
 
+    /// Copies the capacity of this builder.
+    #[inline]
+    fn clone_empty(&self) -> Self {
+        Self {
+            capacity: self.capacity,
+            ..Default::default()
+        }
+    }
+
     #[inline]
     fn extract(&mut self) -> Option<&mut C> {
         if let Some(container) = self.pending.pop_front() {
@@ -184,6 +191,12 @@ impl<C: Container> ContainerBuilder for CapacityContainerBuilder<C> {
 }
 
 impl<C: Container> CapacityContainerBuilder<C> {
+    /// The number of records held by the builder and not yet extracted.
+    #[inline]
+    pub fn pending_len(&self) -> usize {
+        self.current.len() + self.pending.iter().map(|container| container.len()).sum::<usize>()
+    }
+
     /// Push a pre-formed container at this builder. This exists to maintain
     /// API compatibility.
     #[inline]
@@ -204,6 +217,218 @@ impl<C: Container> CapacityContainerBuilder<C> {
     }
 }
 
+impl<C: SizableContainer> CapacityContainerBuilder<C> {
+    /// Allocates a builder that ships containers once they hold `capacity` records.
+    ///
+    /// Smaller capacities reduce latency at the expense of throughput. The default builder
+    /// uses the container's preferred capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        assert!(capacity > 0, "CapacityContainerBuilder requires a positive capacity");
+        Self {
+            capacity: Some(capacity),
+            ..Default::default()
+        }
+    }
+
+    /// Changes the number of records at which containers are shipped, or restores the preferred capacity with `None`.
+    ///
+    /// The new capacity applies to the container being built, which is shipped immediately if it
+    /// already holds at least `capacity` records. Containers already completed are unaffected.
+    pub fn set_capacity(&mut self, capacity: Option<usize>) {
+        assert!(capacity != Some(0), "CapacityContainerBuilder requires a positive capacity");
+        self.capacity = capacity;
+        if !self.current.is_empty() && self.capacity.map_or(false, |capacity| self.current.len() >= capacity) {
+            self.pending.push_back(std::mem::take(&mut self.current));
+        }
+    }
+
+    /// The number of records at which containers are shipped, if not the preferred capacity.
+    #[inline]
+    pub fn capacity(&self) -> Option<usize> {
+        self.capacity
+    }
+
+    /// Reserve space for at least `additional` further elements in the container being built.
+    ///
+    /// The container is shipped once it reaches its capacity, so reserving beyond the preferred
+    /// capacity results in correspondingly larger containers.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.current.reserve(additional);
+    }
+
+    /// Ensures that `current` is allocated with the capacity at which it will be shipped.
+    #[inline]
+    fn prepare(&mut self) {
+        let capacity = self.capacity.unwrap_or_else(C::preferred_capacity);
+        if self.current.capacity() == 0 {
+            self.current = self.empty.take().unwrap_or_default();
+            // Discard any non-uniform capacity container.
+            if self.current.capacity() != capacity {
+                self.current = C::default();
+            }
+            // Protect against non-emptied containers.
+            self.current.clear();
+        }
+        // Ensure capacity
+        if self.current.capacity() < capacity {
+            self.current.reserve(capacity - self.current.len());
+        }
+    }
+
+    /// Indicates that `current` should be shipped.
+    #[inline]
+    fn is_full(&self) -> bool {
+        self.current.len() == self.current.capacity() || self.capacity.map_or(false, |capacity| self.current.len() >= capacity)
+    }
+}
+
+impl<D: Clone + 'static> CapacityContainerBuilder<Vec<D>> {
+    /// Copies the records of `slice` into the builder, as repeated calls to `push_into` would.
+    ///
+    /// Records are copied into each container with `extend_from_slice`, rather than one at a time.
+    pub fn extend_from_slice(&mut self, mut slice: &[D]) {
+        while !slice.is_empty() {
+            self.prepare();
+            let threshold = match self.capacity {
+                Some(capacity) => std::cmp::min(capacity, self.current.capacity()),
+                None => self.current.capacity(),
+            };
+            let count = std::cmp::min(threshold - self.current.len(), slice.len());
+            self.current.extend_from_slice(&slice[.. count]);
+            slice = &slice[count ..];
+            if self.is_full() {
+                self.pending.push_back(std::mem::take(&mut self.current));
+            }
+        }
+    }
+}
+
+/// A container builder that ships containers holding at most a fixed number of records.
+///
+/// Containers supplied to [`Self::push_container`] that exceed the limit are split into several
+/// containers of at most `limit` records, the last of which may be partial, and individual records
+/// are batched in the same way. Bounding the size of containers bounds the work each message
+/// causes downstream. Smaller containers are passed along without copying.
+///
+/// Maintains FIFO order.
+///
+/// # Examples
+///
+/// ```
+/// use timely_container::{ChunkingContainerBuilder, ContainerBuilder};
+///
+/// let mut builder = ChunkingContainerBuilder::with_limit(4);
+/// builder.push_container(&mut (0 .. 10).collect::<Vec<u64>>());
+/// let mut lengths = Vec::new();
+/// while let Some(container) = builder.finish() {
+///     lengths.push(container.len());
+/// }
+/// assert_eq!(lengths, vec![4, 4, 2]);
+/// ```
+pub struct ChunkingContainerBuilder<C> {
+    /// Container that we're writing to.
+    current: C,
+    /// Emtpy allocation.
+    empty: Option<C>,
+    /// Completed containers pending to be sent.
+    pending: VecDeque<C>,
+    /// The maximum number of records in each container.
+    limit: usize,
+}
+
+impl<C: Container> ChunkingContainerBuilder<C> {
+    /// The limit used by [`Default::default`].
+    pub const DEFAULT_LIMIT: usize = 1024;
+
+    /// Allocates a builder that ships containers holding at most `limit` records.
+    pub fn with_limit(limit: usize) -> Self {
+        assert!(limit > 0, "ChunkingContainerBuilder requires a positive limit");
+        Self {
+            current: C::default(),
+            empty: None,
+            pending: VecDeque::new(),
+            limit,
+        }
+    }
+
+    /// The maximum number of records in each container.
+    #[inline]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    /// Push a container at this builder, splitting it if it holds more than `limit` records.
+    ///
+    /// The contents of `container` are taken, leaving it empty.
+    pub fn push_container(&mut self, container: &mut C) where for<'a> C: PushInto<C::Item<'a>> {
+        if container.is_empty() {
+            return;
+        }
+        if self.current.is_empty() && container.len() <= self.limit {
+            let mut empty = self.empty.take().unwrap_or_default();
+            empty.clear();
+            self.pending.push_back(std::mem::replace(container, empty));
+        }
+        else {
+            for item in container.drain() {
+                self.push_into(item);
+            }
+            container.clear();
+        }
+    }
+}
+
+impl<C: Container> Default for ChunkingContainerBuilder<C> {
+    fn default() -> Self {
+        Self::with_limit(Self::DEFAULT_LIMIT)
+    }
+}
+
+impl<C: std::fmt::Debug> std::fmt::Debug for ChunkingContainerBuilder<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChunkingContainerBuilder")
+            .field("current", &self.current)
+            .field("pending", &self.pending)
+            .field("limit", &self.limit)
+            .finish()
+    }
+}
+
+impl<T, C: Container + PushInto<T>> PushInto<T> for ChunkingContainerBuilder<C> {
+    #[inline]
+    fn push_into(&mut self, item: T) {
+        self.current.push(item);
+        if self.current.len() >= self.limit {
+            self.pending.push_back(std::mem::take(&mut self.current));
+        }
+    }
+}
+
+impl<C: Container> ContainerBuilder for ChunkingContainerBuilder<C> {
+    type Container = C;
+
+    #[inline]
+    fn clone_empty(&self) -> Self {
+        Self::with_limit(self.limit)
+    }
+
+    #[inline]
+    fn extract(&mut self) -> Option<&mut C> {
+        self.empty = Some(self.pending.pop_front()?);
+        self.empty.as_mut()
+    }
+
+    #[inline]
+    fn finish(&mut self) -> Option<&mut C> {
+        if !self.current.is_empty() {
+            self.pending.push_back(std::mem::take(&mut self.current));
+        }
+        self.empty = self.pending.pop_front();
+        self.empty.as_mut()
+    }
+}
+
 impl<T: Clone + 'static> Container for Vec<T> {
     type ItemRef<'a> = &'a T where T: 'a;
     type Item<'a> = T where T: 'a;
@@ -24,6 +24,8 @@ pub struct Message<T, C> {
     pub from: usize,
     /// A sequence number for this worker-to-worker stream.
     pub seq: usize,
+    /// A user-defined tag, set by `OutputHandleCore::session_tagged`, and `None` for untagged messages.
+    pub tag: Option<u32>,
 }
 
 impl<T, C> Message<T, C> {
@@ -37,16 +39,23 @@ impl<T, C> Message<T, C> {
 impl<T, C: Container> Message<T, C> {
     /// Creates a new message instance from arguments.
     pub fn new(time: T, data: C, from: usize, seq: usize) -> Self {
-        Message { time, data, from, seq }
+        Message { time, data, from, seq, tag: None }
     }
 
     /// Forms a message, and pushes contents at `pusher`. Replaces `buffer` with what the pusher
     /// leaves in place, or the container's default element. The buffer is cleared.
     #[inline]
     pub fn push_at<P: Push<Bundle<T, C>>>(buffer: &mut C, time: T, pusher: &mut P) {
+        Self::push_at_tagged(buffer, time, None, pusher)
+    }
+
+    /// Forms a message with tag `tag`, and pushes contents at `pusher`, as `push_at`.
+    #[inline]
+    pub fn push_at_tagged<P: Push<Bundle<T, C>>>(buffer: &mut C, time: T, tag: Option<u32>, pusher: &mut P) {
 
         let data = ::std::mem::take(buffer);
-        let message = Message::new(time, data, 0, 0);
+        let mut message = Message::new(time, data, 0, 0);
+        message.tag = tag;
         let mut bundle = Some(Bundle::from_typed(message));
 
         pusher.push(&mut bundle);
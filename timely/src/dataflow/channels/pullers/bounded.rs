@@ -0,0 +1,37 @@
+//! A wrapper which decrements an in-flight count for each message pulled.
+
+use std::marker::PhantomData;
+
+use crate::dataflow::channels::Bundle;
+use crate::dataflow::channels::pushers::bounded::InFlight;
+use crate::communication::Pull;
+
+/// A wrapper which decrements a shared `InFlight` count for each message pulled.
+#[derive(Debug)]
+pub struct Bounded<T, C, P: Pull<Bundle<T, C>>> {
+    pullable: P,
+    in_flight: InFlight,
+    phantom: PhantomData<(T, C)>,
+}
+
+impl<T, C, P: Pull<Bundle<T, C>>> Pull<Bundle<T, C>> for Bounded<T, C, P> {
+    #[inline]
+    fn pull(&mut self) -> &mut Option<Bundle<T, C>> {
+        let message = self.pullable.pull();
+        if message.is_some() {
+            self.in_flight.decrement();
+        }
+        message
+    }
+}
+
+impl<T, C, P: Pull<Bundle<T, C>>> Bounded<T, C, P> {
+    /// Allocates a new `Bounded` from a pullable and a shared count.
+    pub fn new(pullable: P, in_flight: InFlight) -> Self {
+        Bounded {
+            pullable,
+            in_flight,
+            phantom: PhantomData,
+        }
+    }
+}
@@ -12,6 +12,10 @@ use crate::Container;
 pub struct Counter<T: Ord+Clone+'static, C, P: Pull<Bundle<T, C>>> {
     pullable: P,
     consumed: Rc<RefCell<ChangeBatch<T>>>,
+    /// A message pulled by `peek` but not yet returned by `next`.
+    peeked: Option<Bundle<T, C>>,
+    /// Storage for a previously peeked message while it is returned by `next`.
+    current: Option<Bundle<T, C>>,
     phantom: ::std::marker::PhantomData<C>,
 }
 
@@ -45,7 +49,14 @@ impl<T:Ord+Clone+'static, C: Container, P: Pull<Bundle<T, C>>> Counter<T, C, P>
 
     #[inline]
     pub(crate) fn next_guarded(&mut self) -> Option<(ConsumedGuard<T>, &mut Bundle<T, C>)> {
-        if let Some(message) = self.pullable.pull() {
+        let message = if self.peeked.is_some() {
+            self.current = self.peeked.take();
+            &mut self.current
+        }
+        else {
+            self.pullable.pull()
+        };
+        if let Some(message) = message {
             let guard = ConsumedGuard {
                 consumed: Rc::clone(&self.consumed),
                 time: Some(message.time.clone()),
@@ -55,6 +66,26 @@ impl<T:Ord+Clone+'static, C: Container, P: Pull<Bundle<T, C>>> Counter<T, C, P>
         }
         else { None }
     }
+
+    /// Reveals the next timestamp and batch of data, without consuming it.
+    ///
+    /// The message is retained and returned by the next call to `next`.
+    #[inline]
+    pub fn peek(&mut self) -> Option<&Bundle<T, C>> {
+        if self.peeked.is_none() {
+            self.peeked = self.pullable.pull().take();
+        }
+        self.peeked.as_ref()
+    }
+
+    /// A lower bound on the number of messages available to `next`.
+    ///
+    /// The `Pull` trait provides no way to inspect queued messages, so only a message retained
+    /// by `peek` is counted.
+    #[inline]
+    pub fn pending_len(&self) -> usize {
+        if self.peeked.is_some() { 1 } else { 0 }
+    }
 }
 
 impl<T:Ord+Clone+'static, C, P: Pull<Bundle<T, C>>> Counter<T, C, P> {
@@ -64,6 +95,8 @@ impl<T:Ord+Clone+'static, C, P: Pull<Bundle<T, C>>> Counter<T, C, P> {
             phantom: ::std::marker::PhantomData,
             pullable,
             consumed: Rc::new(RefCell::new(ChangeBatch::new())),
+            peeked: None,
+            current: None,
         }
     }
     /// A references to shared changes in counts, for cloning or draining.
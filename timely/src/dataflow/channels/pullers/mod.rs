@@ -1,5 +1,7 @@
 pub use self::counter::Counter;
+pub use self::bounded::Bounded;
 pub mod counter;
+pub mod bounded;
 
 
 // pub trait Pullable<T, D> {
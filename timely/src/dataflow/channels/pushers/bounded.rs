@@ -0,0 +1,68 @@
+//! A wrapper which counts the messages pushed past, for cooperative backpressure.
+
+use std::marker::PhantomData;
+use std::rc::Rc;
+use std::cell::Cell;
+
+use crate::dataflow::channels::Bundle;
+use crate::communication::Push;
+
+/// A shared count of messages pushed but not yet pulled along a channel.
+///
+/// The count is incremented by a `pushers::Bounded` and decremented by the paired
+/// `pullers::Bounded`. It is only a cooperative signal, and has no effect on progress tracking.
+/// The count is local to the worker, and is only exact when the pusher and puller are on the
+/// same worker, as with the `Pipeline` pact.
+#[derive(Clone, Debug)]
+pub struct InFlight {
+    count: Rc<Cell<usize>>,
+    bound: usize,
+}
+
+impl InFlight {
+    /// Allocates a new count with a bound of `bound` messages.
+    pub fn new(bound: usize) -> Self {
+        InFlight {
+            count: Rc::new(Cell::new(0)),
+            bound,
+        }
+    }
+    /// The number of messages pushed but not yet pulled.
+    #[inline] pub fn count(&self) -> usize { self.count.get() }
+    /// The number of in-flight messages above which sources should throttle.
+    #[inline] pub fn bound(&self) -> usize { self.bound }
+    /// Returns true iff the number of in-flight messages has reached the bound.
+    #[inline] pub fn is_full(&self) -> bool { self.count() >= self.bound }
+
+    #[inline] pub(crate) fn increment(&self) { self.count.set(self.count.get() + 1); }
+    #[inline] pub(crate) fn decrement(&self) { self.count.set(self.count.get().saturating_sub(1)); }
+}
+
+/// A wrapper which increments a shared `InFlight` count for each message pushed.
+#[derive(Debug)]
+pub struct Bounded<T, C, P: Push<Bundle<T, C>>> {
+    pushee: P,
+    in_flight: InFlight,
+    phantom: PhantomData<(T, C)>,
+}
+
+impl<T, C, P: Push<Bundle<T, C>>> Push<Bundle<T, C>> for Bounded<T, C, P> {
+    #[inline]
+    fn push(&mut self, message: &mut Option<Bundle<T, C>>) {
+        if message.is_some() {
+            self.in_flight.increment();
+        }
+        self.pushee.push(message);
+    }
+}
+
+impl<T, C, P: Push<Bundle<T, C>>> Bounded<T, C, P> {
+    /// Allocates a new `Bounded` from a pushee and a shared count.
+    pub fn new(pushee: P, in_flight: InFlight) -> Self {
+        Bounded {
+            pushee,
+            in_flight,
+            phantom: PhantomData,
+        }
+    }
+}
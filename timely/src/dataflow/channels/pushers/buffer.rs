@@ -2,7 +2,7 @@
 //! with the performance of batched sends.
 
 use crate::communication::Push;
-use crate::container::{ContainerBuilder, CapacityContainerBuilder, PushInto};
+use crate::container::{ContainerBuilder, CapacityContainerBuilder, ChunkingContainerBuilder, SizableContainer, PushInto};
 use crate::dataflow::channels::{Bundle, Message};
 use crate::dataflow::operators::Capability;
 use crate::progress::Timestamp;
@@ -12,23 +12,59 @@ use crate::Container;
 ///
 /// The `Buffer` type should be used by calling `session` with a time, which checks whether
 /// data must be flushed and creates a `Session` object which allows sending at the given time.
-#[derive(Debug)]
-pub struct Buffer<T, CB, P> {
+pub struct Buffer<T, CB: ContainerBuilder, P> {
     /// The currently open time, if it is open.
     time: Option<T>,
     /// A builder for containers, to send at `self.time`.
     builder: CB,
     /// The pusher to send data downstream.
     pusher: P,
+    /// Numbers of records sent at each time, if enabled by `count_records`.
+    counts: Option<Vec<(T, usize)>>,
+    /// The tag of messages sent at `self.time`.
+    tag: Option<u32>,
+    /// A hook invoked with each container as it is sent, if set by `set_on_flush`.
+    on_flush: Option<Box<dyn FnMut(&CB::Container)>>,
 }
 
-impl<T, CB: Default, P> Buffer<T, CB, P> {
+impl<T: ::std::fmt::Debug, CB: ContainerBuilder + ::std::fmt::Debug, P: ::std::fmt::Debug> ::std::fmt::Debug for Buffer<T, CB, P> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        f.debug_struct("Buffer")
+            .field("time", &self.time)
+            .field("builder", &self.builder)
+            .field("pusher", &self.pusher)
+            .field("counts", &self.counts)
+            .field("tag", &self.tag)
+            .field("on_flush", &self.on_flush.is_some())
+            .finish()
+    }
+}
+
+impl<T, CB: ContainerBuilder, P> Buffer<T, CB, P> {
     /// Creates a new `Buffer`.
     pub fn new(pusher: P) -> Self {
         Self {
             time: None,
             builder: Default::default(),
             pusher,
+            counts: None,
+            tag: None,
+            on_flush: None,
+        }
+    }
+
+    /// Creates a new `Buffer` which uses `builder` to form containers.
+    ///
+    /// This allows a configured builder, for example one with a specific capacity, in place
+    /// of the default builder.
+    pub fn with_builder(pusher: P, builder: CB) -> Self {
+        Self {
+            time: None,
+            builder,
+            pusher,
+            counts: None,
+            tag: None,
+            on_flush: None,
         }
     }
 
@@ -42,6 +78,31 @@ impl<T, CB: Default, P> Buffer<T, CB, P> {
     pub fn builder(&self) -> &CB {
         &self.builder
     }
+
+    /// Starts counting the records sent at each time, for retrieval with `take_record_counts`.
+    ///
+    /// Counting is disabled by default, as it adds work for each container sent.
+    pub fn count_records(&mut self) {
+        if self.counts.is_none() {
+            self.counts = Some(Vec::new());
+        }
+    }
+
+    /// Removes and returns the numbers of records sent at each time since the previous call.
+    ///
+    /// Returns an empty list if counting has not been enabled with `count_records`.
+    pub fn take_record_counts(&mut self) -> Vec<(T, usize)> {
+        self.counts.as_mut().map(::std::mem::take).unwrap_or_default()
+    }
+
+    /// Sets a hook to be invoked with each container as it is sent.
+    ///
+    /// The hook fires both for containers sent because the builder completed them, and for
+    /// those sent when the buffer is flushed, for example by `cease`.
+    pub fn set_on_flush<F: FnMut(&CB::Container)+'static>(&mut self, hook: F) {
+        self.on_flush = Some(Box::new(hook));
+    }
+
 }
 
 impl<T, C: Container, P: Push<Bundle<T, C>>> Buffer<T, CapacityContainerBuilder<C>, P> where T: Eq+Clone {
@@ -57,6 +118,16 @@ impl<T, C: Container, P: Push<Bundle<T, C>>> Buffer<T, CapacityContainerBuilder<
         self.autoflush_session_with_builder(cap)
     }
 
+    /// Changes the number of records at which the builder ships containers.
+    ///
+    /// Any container completed by the change is sent at the current time.
+    pub fn set_capacity(&mut self, capacity: Option<usize>) where C: SizableContainer {
+        self.builder.set_capacity(capacity);
+        if self.time.is_some() {
+            self.extract_and_send();
+        }
+    }
+
     /// Gives an entire container at the current time.
     fn give_container(&mut self, container: &mut C) {
         if !container.is_empty() {
@@ -69,20 +140,48 @@ impl<T, C: Container, P: Push<Bundle<T, C>>> Buffer<T, CapacityContainerBuilder<
 impl<T, CB: ContainerBuilder, P: Push<Bundle<T, CB::Container>>> Buffer<T, CB, P> where T: Eq+Clone {
     /// Returns a `Session`, which accepts data to send at the associated time
     pub fn session_with_builder(&mut self, time: &T) -> Session<T, CB, P> {
-        if let Some(true) = self.time.as_ref().map(|x| x != time) { self.flush(); }
-        self.time = Some(time.clone());
+        self.open(time, None);
+        Session { buffer: self }
+    }
+
+    /// Returns a `Session`, which accepts data to send at the associated time in messages tagged with `tag`.
+    ///
+    /// Data buffered with a different time or tag is sent first, so that each message carries a single tag.
+    pub fn session_tagged(&mut self, time: &T, tag: u32) -> Session<T, CB, P> {
+        self.open(time, Some(tag));
         Session { buffer: self }
     }
 
+    /// Prepares to buffer data at `time` with `tag`, first sending data buffered with another time or tag.
+    fn open(&mut self, time: &T, tag: Option<u32>) {
+        if let Some(true) = self.time.as_ref().map(|x| x != time || self.tag != tag) { self.flush(); }
+        self.time = Some(time.clone());
+        self.tag = tag;
+    }
+
     /// Allocates a new `AutoflushSession` which flushes itself on drop.
     pub fn autoflush_session_with_builder(&mut self, cap: Capability<T>) -> AutoflushSession<T, CB, P> where T: Timestamp {
-        if let Some(true) = self.time.as_ref().map(|x| x != cap.time()) { self.flush(); }
-        self.time = Some(cap.time().clone());
+        self.open(cap.time(), None);
         AutoflushSession {
             buffer: self,
             _capability: cap,
         }
     }
+
+    /// Returns a `MaxLenSession`, which sends containers of at most `max_len` records at the associated time.
+    ///
+    /// Any records already buffered are sent first, so that they do not count against the limit.
+    pub fn max_len_session(&mut self, time: &T, max_len: usize) -> MaxLenSession<T, CB, P> {
+        assert!(max_len > 0, "MaxLenSession requires a positive maximum length");
+        if self.time.is_some() { self.flush(); }
+        self.time = Some(time.clone());
+        self.tag = None;
+        MaxLenSession {
+            buffer: self,
+            max_len,
+            count: 0,
+        }
+    }
 }
 
 impl<T, CB: ContainerBuilder, P: Push<Bundle<T, CB::Container>>> Buffer<T, CB, P> where T: Eq+Clone {
@@ -97,20 +196,42 @@ impl<T, CB: ContainerBuilder, P: Push<Bundle<T, CB::Container>>> Buffer<T, CB, P
     fn extract_and_send(&mut self) {
         while let Some(container) = self.builder.extract() {
             let time = self.time.as_ref().unwrap().clone();
-            Message::push_at(container, time, &mut self.pusher);
+            if let Some(counts) = self.counts.as_mut() {
+                tally(counts, &time, container.len());
+            }
+            if let Some(hook) = self.on_flush.as_mut() {
+                hook(container);
+            }
+            Message::push_at_tagged(container, time, self.tag, &mut self.pusher);
         }
     }
 
     /// Flush the builder, forcing all its contents to be written.
+    ///
+    /// Unlike `cease`, this does not push a `None` to `self.pusher`.
     #[inline]
-    fn flush(&mut self) {
+    pub fn flush(&mut self) {
         while let Some(container) = self.builder.finish() {
             let time = self.time.as_ref().unwrap().clone();
-            Message::push_at(container, time, &mut self.pusher);
+            if let Some(counts) = self.counts.as_mut() {
+                tally(counts, &time, container.len());
+            }
+            if let Some(hook) = self.on_flush.as_mut() {
+                hook(container);
+            }
+            Message::push_at_tagged(container, time, self.tag, &mut self.pusher);
         }
     }
 }
 
+/// Adds `records` to the count for `time`.
+fn tally<T: Eq+Clone>(counts: &mut Vec<(T, usize)>, time: &T, records: usize) {
+    match counts.iter_mut().find(|(counted, _)| counted == time) {
+        Some((_, count)) => *count += records,
+        None => counts.push((time.clone(), records)),
+    }
+}
+
 impl<T, CB, P, D> PushInto<D> for Buffer<T, CB, P>
 where
     T: Eq+Clone,
@@ -129,7 +250,7 @@ where
 /// The `Session` struct provides the user-facing interface to an operator output, namely
 /// the `Buffer` type. A `Session` wraps a session of output at a specified time, and
 /// avoids what would otherwise be a constant cost of checking timestamp equality.
-pub struct Session<'a, T, CB, P> {
+pub struct Session<'a, T, CB: ContainerBuilder, P> {
     buffer: &'a mut Buffer<T, CB, P>,
 }
 
@@ -144,6 +265,92 @@ where
     }
 }
 
+impl<'a, T, C: SizableContainer, P> Session<'a, T, CapacityContainerBuilder<C>, P>
+where
+    T: Eq + Clone + 'a,
+    P: Push<Bundle<T, C>> + 'a,
+{
+    /// Reserve space for at least `additional` records to be given in the [Session].
+    ///
+    /// For `Vec`-based containers this corresponds to `Vec::reserve`, and avoids repeated
+    /// reallocation when a large number of records will be sent at the same time.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.buffer.builder.reserve(additional);
+    }
+}
+
+impl<'a, T, D: Clone + 'static, P> Session<'a, T, CapacityContainerBuilder<Vec<D>>, P>
+where
+    T: Eq + Clone + 'a,
+    P: Push<Bundle<T, Vec<D>>> + 'a,
+{
+    /// Provides a slice of records at the time specified by the [Session].
+    ///
+    /// The records are cloned into the buffer with `extend_from_slice`, rather than one at
+    /// a time, and any containers filled along the way are sent.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::generic::operator::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary(Pipeline, "GiveSlice", |_cap, _info| |input, output| {
+    ///                input.for_each(|time, data| {
+    ///                    output.session(&time).give_slice(&data[..]);
+    ///                });
+    ///            })
+    ///            .inspect(|x: &u64| println!("seen: {:?}", x));
+    /// });
+    /// ```
+    #[inline]
+    pub fn give_slice(&mut self, slice: &[D]) {
+        self.buffer.builder.extend_from_slice(slice);
+        self.buffer.extract_and_send();
+    }
+    /// Provides a clone of one record at the time specified by the [Session].
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::generic::operator::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary(Pipeline, "GiveRef", |_cap, _info| |input, output| {
+    ///                input.for_each(|time, data| {
+    ///                    let mut session = output.session(&time);
+    ///                    for datum in data.iter() {
+    ///                        session.give_ref(datum);
+    ///                    }
+    ///                });
+    ///            })
+    ///            .inspect(|x: &u64| println!("seen: {:?}", x));
+    /// });
+    /// ```
+    #[inline]
+    pub fn give_ref(&mut self, datum: &D) {
+        self.give(datum.clone());
+    }
+}
+
+impl<'a, T, C: Container, P> Session<'a, T, ChunkingContainerBuilder<C>, P>
+where
+    T: Eq + Clone + 'a,
+    P: Push<Bundle<T, C>> + 'a,
+    for<'b> C: PushInto<C::Item<'b>>,
+{
+    /// Provide a container at the time specified by the [Session], split into chunks of at most the builder's limit.
+    pub fn give_container(&mut self, container: &mut C) {
+        self.buffer.builder.push_container(container);
+        self.buffer.extract_and_send();
+    }
+}
+
 impl<'a, T, CB, P> Session<'a, T, CB, P>
 where
     T: Eq + Clone + 'a,
@@ -187,6 +394,65 @@ where
     }
 }
 
+/// A session which sends containers of at most a fixed number of records.
+///
+/// The session counts the records it is given, and flushes the underlying buffer each time the
+/// count reaches its maximum length, independently of when the container builder would ship.
+/// No container sent through the session holds more than the maximum number of records, which
+/// bounds the work a downstream operator performs for each message.
+pub struct MaxLenSession<'a, T, CB: ContainerBuilder, P> {
+    buffer: &'a mut Buffer<T, CB, P>,
+    max_len: usize,
+    count: usize,
+}
+
+impl<'a, T, CB, P> MaxLenSession<'a, T, CB, P>
+where
+    T: Eq + Clone + 'a,
+    CB: ContainerBuilder + 'a,
+    P: Push<Bundle<T, CB::Container>> + 'a
+{
+    /// Provides one record at the time specified by the `MaxLenSession`.
+    #[inline]
+    pub fn give<D>(&mut self, data: D) where CB: PushInto<D> {
+        self.push_into(data);
+    }
+
+    /// Provides an iterator of records at the time specified by the `MaxLenSession`.
+    #[inline]
+    pub fn give_iterator<I>(&mut self, iter: I)
+    where
+        I: Iterator,
+        CB: PushInto<I::Item>,
+    {
+        for item in iter {
+            self.push_into(item);
+        }
+    }
+
+    /// The maximum number of records in a container sent by the session.
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+}
+
+impl<'a, T, CB, P, D> PushInto<D> for MaxLenSession<'a, T, CB, P>
+where
+    T: Eq + Clone + 'a,
+    CB: ContainerBuilder + PushInto<D> + 'a,
+    P: Push<Bundle<T, CB::Container>> + 'a,
+{
+    #[inline]
+    fn push_into(&mut self, item: D) {
+        self.buffer.push_into(item);
+        self.count += 1;
+        if self.count >= self.max_len {
+            self.buffer.flush();
+            self.count = 0;
+        }
+    }
+}
+
 /// A session which will flush itself when dropped.
 pub struct AutoflushSession<'a, T, CB, P>
 where
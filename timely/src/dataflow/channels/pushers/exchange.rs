@@ -14,6 +14,8 @@ where
     pushers: Vec<P>,
     buffers: Vec<C>,
     current: Option<T>,
+    /// The tag of the messages whose data are buffered, preserved in the messages sent.
+    tag: Option<u32>,
     hash_func: H,
 }
 
@@ -32,13 +34,14 @@ where
             hash_func: key,
             buffers,
             current: None,
+            tag: None,
         }
     }
     #[inline]
     fn flush(&mut self, index: usize) {
         if !self.buffers[index].is_empty() {
             if let Some(ref time) = self.current {
-                Message::push_at(&mut self.buffers[index], time.clone(), &mut self.pushers[index]);
+                Message::push_at_tagged(&mut self.buffers[index], time.clone(), self.tag, &mut self.pushers[index]);
             }
         }
     }
@@ -59,15 +62,17 @@ where
 
             let message = message.as_mut();
             let time = &message.time;
+            let tag = message.tag;
             let data = &mut message.data;
 
-            // if the time isn't right, flush everything.
-            if self.current.as_ref().map_or(false, |x| x != time) {
+            // if the time or tag isn't right, flush everything.
+            if self.current.as_ref().map_or(false, |x| x != time || self.tag != tag) {
                 for index in 0..self.pushers.len() {
                     self.flush(index);
                 }
             }
             self.current = Some(time.clone());
+            self.tag = tag;
 
             let hash_func = &mut self.hash_func;
 
@@ -79,7 +84,7 @@ where
                     &mut self.buffers,
                     move |datum| ((hash_func)(datum) & mask) as usize,
                     |index, buffer| {
-                            Message::push_at(buffer, time.clone(), &mut pushers[index]);
+                            Message::push_at_tagged(buffer, time.clone(), tag, &mut pushers[index]);
                     }
                 );
             }
@@ -91,7 +96,7 @@ where
                     &mut self.buffers,
                     move |datum| ((hash_func)(datum) % num_pushers) as usize,
                     |index, buffer| {
-                        Message::push_at(buffer, time.clone(), &mut pushers[index]);
+                        Message::push_at_tagged(buffer, time.clone(), tag, &mut pushers[index]);
                     }
                 );
             }
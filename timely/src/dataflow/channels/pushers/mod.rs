@@ -1,8 +1,12 @@
-pub use self::tee::{Tee, TeeHelper};
+pub use self::tee::{Tee, TeeHelper, RemovablePusher};
 pub use self::exchange::Exchange;
 pub use self::counter::Counter;
+pub use self::bounded::{Bounded, InFlight};
+pub use self::timer::Timer;
 
 pub mod tee;
 pub mod exchange;
 pub mod counter;
+pub mod bounded;
+pub mod timer;
 pub mod buffer;
@@ -2,14 +2,31 @@
 
 use std::cell::RefCell;
 use std::fmt::{self, Debug};
-use std::rc::Rc;
+use std::rc::{Rc, Weak};
 
 use crate::dataflow::channels::{Bundle, Message};
 
 use crate::communication::Push;
 use crate::{Container, Data};
 
-type PushList<T, C> = Rc<RefCell<Vec<Box<dyn Push<Bundle<T, C>>>>>>;
+type PushList<T, C> = Rc<RefCell<Pushers<T, C>>>;
+
+/// A list of `Box<Push>`, each paired with an identifier which allows its removal.
+struct Pushers<T, C> {
+    /// The identifier to assign to the next pusher.
+    next_id: usize,
+    /// The pushers, and their identifiers.
+    list: Vec<(usize, Box<dyn Push<Bundle<T, C>>>)>,
+}
+
+impl<T, C> Pushers<T, C> {
+    fn new() -> Self {
+        Pushers { next_id: 0, list: Vec::new() }
+    }
+    fn len(&self) -> usize {
+        self.list.len()
+    }
+}
 
 /// Wraps a shared list of `Box<Push>` to forward pushes to. Owned by `Stream`.
 pub struct Tee<T, C> {
@@ -24,17 +41,17 @@ impl<T: Data, C: Container> Push<Bundle<T, C>> for Tee<T, C> {
         if let Some(message) = message {
             for index in 1..pushers.len() {
                 self.buffer.clone_from(&message.data);
-                Message::push_at(&mut self.buffer, message.time.clone(), &mut pushers[index-1]);
+                Message::push_at_tagged(&mut self.buffer, message.time.clone(), message.tag, &mut pushers.list[index-1].1);
             }
         }
         else {
             for index in 1..pushers.len() {
-                pushers[index-1].push(&mut None);
+                pushers.list[index-1].1.push(&mut None);
             }
         }
         if pushers.len() > 0 {
             let last = pushers.len() - 1;
-            pushers[last].push(message);
+            pushers.list[last].1.push(message);
         }
     }
 }
@@ -42,7 +59,7 @@ impl<T: Data, C: Container> Push<Bundle<T, C>> for Tee<T, C> {
 impl<T, C: Container> Tee<T, C> {
     /// Allocates a new pair of `Tee` and `TeeHelper`.
     pub fn new() -> (Tee<T, C>, TeeHelper<T, C>) {
-        let shared = Rc::new(RefCell::new(Vec::new()));
+        let shared = Rc::new(RefCell::new(Pushers::new()));
         let port = Tee {
             buffer: Default::default(),
             shared: shared.clone(),
@@ -86,8 +103,89 @@ pub struct TeeHelper<T, C> {
 
 impl<T, C> TeeHelper<T, C> {
     /// Adds a new `Push` implementor to the list of recipients shared with a `Stream`.
-    pub fn add_pusher<P: Push<Bundle<T, C>>+'static>(&self, pusher: P) {
-        self.shared.borrow_mut().push(Box::new(pusher));
+    ///
+    /// Returns an identifier for the pusher, which can be supplied to `remove_pusher`.
+    pub fn add_pusher<P: Push<Bundle<T, C>>+'static>(&self, pusher: P) -> usize {
+        let mut shared = self.shared.borrow_mut();
+        let id = shared.next_id;
+        shared.next_id += 1;
+        shared.list.push((id, Box::new(pusher)));
+        id
+    }
+
+    /// Adds a new `Push` implementor, returning a handle which removes it when dropped.
+    pub fn add_removable_pusher<P: Push<Bundle<T, C>>+'static>(&self, pusher: P) -> RemovablePusher<T, C> {
+        let id = self.add_pusher(pusher);
+        RemovablePusher {
+            shared: Rc::downgrade(&self.shared),
+            id: Some(id),
+        }
+    }
+
+    /// Removes the pusher with identifier `id`, returning `true` if it was present.
+    ///
+    /// The pusher is flushed before it is dropped, so that data it has buffered is not lost.
+    pub fn remove_pusher(&self, id: usize) -> bool {
+        remove_pusher(&self.shared, id)
+    }
+
+    /// The number of pushers currently in the list of recipients.
+    pub fn pusher_count(&self) -> usize {
+        self.shared.borrow().list.len()
+    }
+}
+
+/// Removes the pusher with identifier `id` from `shared`, flushing it first.
+fn remove_pusher<T, C>(shared: &RefCell<Pushers<T, C>>, id: usize) -> bool {
+    let removed = {
+        let mut shared = shared.borrow_mut();
+        shared.list.iter().position(|(i, _)| *i == id).map(|index| shared.list.remove(index).1)
+    };
+    if let Some(mut pusher) = removed {
+        pusher.push(&mut None);
+        true
+    }
+    else {
+        false
+    }
+}
+
+/// A handle to a pusher registered with a `TeeHelper`, which removes the pusher when dropped.
+///
+/// The handle does not keep the list of pushers alive; if the stream's source has been dropped,
+/// removal has no effect.
+#[must_use = "dropping the handle immediately removes the pusher"]
+pub struct RemovablePusher<T, C> {
+    shared: Weak<RefCell<Pushers<T, C>>>,
+    id: Option<usize>,
+}
+
+impl<T, C> RemovablePusher<T, C> {
+    /// The identifier of the pusher, as accepted by `TeeHelper::remove_pusher`.
+    pub fn id(&self) -> Option<usize> { self.id }
+
+    /// Removes the pusher, returning `true` if it was present.
+    pub fn remove(mut self) -> bool {
+        self.remove_inner()
+    }
+
+    fn remove_inner(&mut self) -> bool {
+        match (self.id.take(), self.shared.upgrade()) {
+            (Some(id), Some(shared)) => remove_pusher(&shared, id),
+            _ => false,
+        }
+    }
+}
+
+impl<T, C> Drop for RemovablePusher<T, C> {
+    fn drop(&mut self) {
+        self.remove_inner();
+    }
+}
+
+impl<T, C> Debug for RemovablePusher<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RemovablePusher").field("id", &self.id).finish()
     }
 }
 
@@ -0,0 +1,66 @@
+//! A wrapper which measures the time spent pushing to a downstream pusher.
+
+use std::marker::PhantomData;
+use std::time::{Duration, Instant};
+
+use crate::dataflow::channels::Bundle;
+use crate::communication::Push;
+use crate::logging::{TimelyLogger as Logger, PushTimingEvent};
+
+/// A wrapper which accumulates the wall-clock time spent in downstream `push` calls.
+///
+/// The accumulated time is logged as a `PushTimingEvent` when the channel is flushed, which
+/// locates the channels whose recipients are slow to accept data. Without a logger the
+/// wrapper forwards messages without consulting the clock.
+#[derive(Debug)]
+pub struct Timer<T, C, P: Push<Bundle<T, C>>> {
+    pushee: P,
+    channel: usize,
+    logging: Option<Logger>,
+    elapsed: Duration,
+    messages: usize,
+    phantom: PhantomData<(T, C)>,
+}
+
+impl<T, C, P: Push<Bundle<T, C>>> Push<Bundle<T, C>> for Timer<T, C, P> {
+    #[inline]
+    fn push(&mut self, message: &mut Option<Bundle<T, C>>) {
+        if let Some(logging) = &self.logging {
+            let is_flush = message.is_none();
+            let start = Instant::now();
+            self.pushee.push(message);
+            self.elapsed += start.elapsed();
+            if is_flush {
+                if self.messages > 0 {
+                    logging.log(PushTimingEvent {
+                        channel: self.channel,
+                        messages: self.messages,
+                        elapsed: self.elapsed,
+                    });
+                }
+                self.messages = 0;
+                self.elapsed = Duration::default();
+            }
+            else {
+                self.messages += 1;
+            }
+        }
+        else {
+            self.pushee.push(message);
+        }
+    }
+}
+
+impl<T, C, P: Push<Bundle<T, C>>> Timer<T, C, P> {
+    /// Allocates a new `Timer` for channel `channel`, reporting to `logging` if present.
+    pub fn new(pushee: P, channel: usize, logging: Option<Logger>) -> Self {
+        Timer {
+            pushee,
+            channel,
+            logging,
+            elapsed: Duration::default(),
+            messages: 0,
+            phantom: PhantomData,
+        }
+    }
+}
@@ -32,6 +32,29 @@ use crate::progress::Timestamp;
 use crate::progress::ChangeBatch;
 use crate::scheduling::Activations;
 use crate::dataflow::channels::pullers::counter::ConsumedGuard;
+use crate::logging::{TimelyCapabilityLogger, CapabilityEvent, CapabilityAction};
+
+/// Reports the capability actions of an operator to a logger.
+#[derive(Clone, Debug)]
+pub(crate) struct CapabilityLogger {
+    logger: TimelyCapabilityLogger,
+    operator: usize,
+}
+
+impl CapabilityLogger {
+    /// Allocates a new `CapabilityLogger` for the operator with worker-unique identifier `operator`.
+    pub(crate) fn new(logger: TimelyCapabilityLogger, operator: usize) -> Self {
+        CapabilityLogger { logger, operator }
+    }
+    /// Logs `action` taken on a capability for `time`.
+    fn log<T: Timestamp>(&self, time: &T, action: CapabilityAction) {
+        self.logger.log(CapabilityEvent {
+            operator: self.operator,
+            time: Box::new(time.clone()),
+            action,
+        });
+    }
+}
 
 /// An internal trait expressing the capability to send messages with a given timestamp.
 pub trait CapabilityTrait<T: Timestamp> {
@@ -62,6 +85,7 @@ impl<'a, T: Timestamp, C: CapabilityTrait<T>> CapabilityTrait<T> for &'a mut C {
 pub struct Capability<T: Timestamp> {
     time: T,
     internal: Rc<RefCell<ChangeBatch<T>>>,
+    logging: Option<CapabilityLogger>,
 }
 
 impl<T: Timestamp> CapabilityTrait<T> for Capability<T> {
@@ -75,11 +99,20 @@ impl<T: Timestamp> Capability<T> {
     /// Creates a new capability at `time` while incrementing (and keeping a reference to) the provided
     /// [`ChangeBatch`].
     pub(crate) fn new(time: T, internal: Rc<RefCell<ChangeBatch<T>>>) -> Self {
+        Self::new_logged(time, internal, None)
+    }
+
+    /// Creates a new capability as `new` does, reporting its actions to `logging` if present.
+    pub(crate) fn new_logged(time: T, internal: Rc<RefCell<ChangeBatch<T>>>, logging: Option<CapabilityLogger>) -> Self {
         internal.borrow_mut().update(time.clone(), 1);
+        if let Some(logging) = &logging {
+            logging.log(&time, CapabilityAction::Create);
+        }
 
         Self {
             time,
             internal,
+            logging,
         }
     }
 
@@ -118,7 +151,7 @@ impl<T: Timestamp> Capability<T> {
     /// Returns [`None`] `self.time` is not less or equal to `new_time`.
     pub fn try_delayed(&self, new_time: &T) -> Option<Capability<T>> {
         if self.time.less_equal(new_time) {
-            Some(Self::new(new_time.clone(), self.internal.clone()))
+            Some(Self::new_logged(new_time.clone(), self.internal.clone(), self.logging.clone()))
         } else {
             None
         }
@@ -151,8 +184,14 @@ impl<T: Timestamp> Capability<T> {
     ///
     /// Returns a [DowngradeError] if `self.time` is not less or equal to `new_time`.
     pub fn try_downgrade(&mut self, new_time: &T) -> Result<(), DowngradeError> {
-        if let Some(new_capability) = self.try_delayed(new_time) {
-            *self = new_capability;
+        if self.time.less_equal(new_time) {
+            // Log a downgrade, rather than the creation of one capability and the drop of another.
+            let logging = self.logging.take();
+            *self = Self::new(new_time.clone(), self.internal.clone());
+            if let Some(logging) = &logging {
+                logging.log(new_time, CapabilityAction::Downgrade);
+            }
+            self.logging = logging;
             Ok(())
         } else {
             Err(DowngradeError(()))
@@ -166,12 +205,15 @@ impl<T: Timestamp> Capability<T> {
 impl<T: Timestamp> Drop for Capability<T> {
     fn drop(&mut self) {
         self.internal.borrow_mut().update(self.time.clone(), -1);
+        if let Some(logging) = &self.logging {
+            logging.log(&self.time, CapabilityAction::Drop);
+        }
     }
 }
 
 impl<T: Timestamp> Clone for Capability<T> {
     fn clone(&self) -> Capability<T> {
-        Self::new(self.time.clone(), self.internal.clone())
+        Self::new_logged(self.time.clone(), self.internal.clone(), self.logging.clone())
     }
 }
 
@@ -240,6 +282,10 @@ pub struct InputCapability<T: Timestamp> {
     summaries: Rc<RefCell<Vec<Antichain<T::Summary>>>>,
     /// A drop guard that updates the consumed capability this InputCapability refers to on drop
     consumed_guard: ConsumedGuard<T>,
+    /// Reports the capabilities minted from this capability, if present.
+    logging: Option<CapabilityLogger>,
+    /// The index of the input through which the capability was received.
+    port: usize,
 }
 
 impl<T: Timestamp> CapabilityTrait<T> for InputCapability<T> {
@@ -256,11 +302,13 @@ impl<T: Timestamp> CapabilityTrait<T> for InputCapability<T> {
 impl<T: Timestamp> InputCapability<T> {
     /// Creates a new capability reference at `time` while incrementing (and keeping a reference to)
     /// the provided [`ChangeBatch`].
-    pub(crate) fn new(internal: CapabilityUpdates<T>, summaries: Rc<RefCell<Vec<Antichain<T::Summary>>>>, guard: ConsumedGuard<T>) -> Self {
+    pub(crate) fn new(internal: CapabilityUpdates<T>, summaries: Rc<RefCell<Vec<Antichain<T::Summary>>>>, guard: ConsumedGuard<T>, logging: Option<CapabilityLogger>, port: usize) -> Self {
         InputCapability {
             internal,
             summaries,
             consumed_guard: guard,
+            logging,
+            port,
         }
     }
 
@@ -269,6 +317,14 @@ impl<T: Timestamp> InputCapability<T> {
         self.consumed_guard.time()
     }
 
+    /// The index of the operator input through which this capability was received.
+    ///
+    /// Inputs are numbered from zero in the order they were added to the operator, which
+    /// allows operators merging several inputs to record the provenance of each capability.
+    pub fn port(&self) -> usize {
+        self.port
+    }
+
     /// Makes a new capability for a timestamp `new_time` greater or equal to the timestamp of
     /// the source capability (`self`).
     ///
@@ -279,11 +335,31 @@ impl<T: Timestamp> InputCapability<T> {
 
     /// Delays capability for a specific output port.
     pub fn delayed_for_output(&self, new_time: &T, output_port: usize) -> Capability<T> {
+        match self.try_delayed_for_output(new_time, output_port) {
+            Some(capability) => capability,
+            None => panic!("Attempted to delay to a time ({:?}) not greater or equal to the operators input-output summary ({:?}) applied to the capabilities time ({:?})", new_time, self.summaries.borrow()[output_port], self.time()),
+        }
+    }
+
+    /// Attempts to make a new capability for a timestamp `new_time` greater or equal to the
+    /// timestamp of the source capability (`self`).
+    ///
+    /// Returns [`None`] if `new_time` is not greater or equal to the time of `self`, after
+    /// the summary to output zero has been applied.
+    pub fn try_delayed(&self, new_time: &T) -> Option<Capability<T>> {
+        self.try_delayed_for_output(new_time, 0)
+    }
+
+    /// Attempts to delay the capability for a specific output port.
+    ///
+    /// Returns [`None`] if `new_time` is not greater or equal to the time of `self`, after
+    /// the summary to `output_port` has been applied.
+    pub fn try_delayed_for_output(&self, new_time: &T, output_port: usize) -> Option<Capability<T>> {
         use crate::progress::timestamp::PathSummary;
         if self.summaries.borrow()[output_port].iter().flat_map(|summary| summary.results_in(self.time())).any(|time| time.less_equal(new_time)) {
-            Capability::new(new_time.clone(), self.internal.borrow()[output_port].clone())
+            Some(Capability::new_logged(new_time.clone(), self.internal.borrow()[output_port].clone(), self.logging.clone()))
         } else {
-            panic!("Attempted to delay to a time ({:?}) not greater or equal to the operators input-output summary ({:?}) applied to the capabilities time ({:?})", new_time, self.summaries.borrow()[output_port], self.time());
+            None
         }
     }
 
@@ -305,7 +381,7 @@ impl<T: Timestamp> InputCapability<T> {
         use crate::progress::timestamp::PathSummary;
         let self_time = self.time().clone();
         if self.summaries.borrow()[output_port].iter().flat_map(|summary| summary.results_in(&self_time)).any(|time| time.less_equal(&self_time)) {
-            Capability::new(self_time, self.internal.borrow()[output_port].clone())
+            Capability::new_logged(self_time, self.internal.borrow()[output_port].clone(), self.logging.clone())
         }
         else {
             panic!("Attempted to retain a time ({:?}) not greater or equal to the operators input-output summary ({:?}) applied to the capabilities time ({:?})", self_time, self.summaries.borrow()[output_port], self_time);
@@ -325,6 +401,7 @@ impl<T: Timestamp> Debug for InputCapability<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("InputCapability")
             .field("time", self.time())
+            .field("port", &self.port)
             .field("internal", &"...")
             .finish()
     }
@@ -369,6 +446,17 @@ impl<T: Timestamp> ActivateCapability<T> {
         }
     }
 
+    /// Attempts to create a new delayed capability.
+    ///
+    /// Returns [`None`] if `time` is not greater or equal to the time of `self`.
+    pub fn try_delayed(&self, time: &T) -> Option<Self> {
+        self.capability.try_delayed(time).map(|capability| ActivateCapability {
+            capability,
+            address: self.address.clone(),
+            activations: self.activations.clone(),
+        })
+    }
+
     /// Downgrades this capability.
     pub fn downgrade(&mut self, time: &T) {
         self.capability.downgrade(time);
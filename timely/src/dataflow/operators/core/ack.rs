@@ -0,0 +1,122 @@
+//! Retain capabilities for the times of a stream until they are acknowledged.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+use crate::container::CapacityContainerBuilder;
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::CapabilitySet;
+use crate::dataflow::operators::generic::Operator;
+use crate::dataflow::{Scope, StreamCore};
+use crate::progress::Antichain;
+use crate::Container;
+
+/// Retain capabilities for the times of a stream until they are acknowledged.
+pub trait RetainUntilAcked<G: Scope, C: Container> {
+    /// Passes the stream through unchanged, holding its times open until they are acknowledged.
+    ///
+    /// The operator retains capabilities for the elements of `acks`, an "ack frontier" maintained
+    /// by external code, and so the output frontier does not pass a time until both the input
+    /// frontier and the ack frontier have passed it. A sink that acknowledges times once their
+    /// data are durably stored can then report completion downstream only for stored times.
+    ///
+    /// The ack frontier must only advance, and should start at the minimal timestamp. The operator
+    /// consults it each time it is scheduled, and while it holds capabilities reschedules itself
+    /// every ten milliseconds; use `retain_until_acked_with_interval` to poll at another rate.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use std::cell::RefCell;
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::core::RetainUntilAcked;
+    /// use timely::progress::Antichain;
+    ///
+    /// timely::example(|scope| {
+    ///     // an empty ack frontier acknowledges all times.
+    ///     let acks = Rc::new(RefCell::new(Antichain::new()));
+    ///     (0..10).to_stream(scope)
+    ///            .retain_until_acked(acks)
+    ///            .inspect(|x| println!("seen: {:?}", x));
+    /// });
+    /// ```
+    fn retain_until_acked(&self, acks: Rc<RefCell<Antichain<G::Timestamp>>>) -> StreamCore<G, C> {
+        self.retain_until_acked_with_interval(acks, Duration::from_millis(10))
+    }
+    /// As `retain_until_acked`, consulting the ack frontier at least once every `interval`.
+    ///
+    /// Shorter intervals release acknowledged times sooner, at the cost of scheduling the operator
+    /// more often while times remain unacknowledged.
+    fn retain_until_acked_with_interval(&self, acks: Rc<RefCell<Antichain<G::Timestamp>>>, interval: Duration) -> StreamCore<G, C>;
+}
+
+impl<G: Scope, C: Container> RetainUntilAcked<G, C> for StreamCore<G, C> {
+    fn retain_until_acked_with_interval(&self, acks: Rc<RefCell<Antichain<G::Timestamp>>>, interval: Duration) -> StreamCore<G, C> {
+        let scope = self.scope();
+        self.unary_frontier::<CapacityContainerBuilder<C>, _, _, _>(Pipeline, "RetainUntilAcked", move |capability, info| {
+
+            let activator = scope.activator_for(&info.address[..]);
+            let mut held = CapabilitySet::from_elem(capability);
+            let mut container = Default::default();
+
+            move |input, output| {
+                input.for_each(|time, data| {
+                    data.swap(&mut container);
+                    output.session(&time).give_container(&mut container);
+                });
+
+                // the input frontier holds the output for incomplete times; we hold it for unacknowledged times.
+                held.downgrade(acks.borrow().elements());
+                if !held.is_empty() {
+                    activator.activate_after(interval);
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use crate::dataflow::operators::{Input, Probe};
+    use crate::dataflow::operators::capture::{Capture, Extract};
+    use crate::progress::Antichain;
+    use super::RetainUntilAcked;
+
+    #[test]
+    fn held_until_acked() {
+
+        let (held, captured) = crate::execute_directly(|worker| {
+            let acks = Rc::new(RefCell::new(Antichain::from_elem(0)));
+            let (mut input, probe, captured) = worker.dataflow::<u64, _, _>(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                let retained = stream.retain_until_acked_with_interval(Rc::clone(&acks), Duration::from_millis(1));
+                (input, retained.probe(), retained.capture())
+            });
+            input.send(0);
+            input.advance_to(1);
+            input.send(1);
+            input.advance_to(2);
+            for _ in 0 .. 4 { worker.step(); }
+            // time 0 is complete at the input, but not acknowledged.
+            let mut held = vec![probe.less_equal(&0)];
+            *acks.borrow_mut() = Antichain::from_elem(1);
+            // the operator observes the acknowledgement within the polling interval.
+            while probe.less_equal(&0) { worker.step(); }
+            held.push(probe.less_equal(&0));
+            held.push(probe.less_equal(&1));
+            *acks.borrow_mut() = Antichain::new();
+            input.close();
+            while worker.step() { }
+            (held, captured)
+        });
+
+        assert_eq!(held, vec![true, false, true]);
+        assert_eq!(captured.extract(), vec![(0, vec![0]), (1, vec![1])]);
+    }
+}
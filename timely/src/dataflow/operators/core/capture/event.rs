@@ -4,6 +4,8 @@
 //! stream. There are two types of events, (i) the receipt of data and (ii) reports of progress
 //! of timestamps.
 
+use crate::progress::{ChangeBatch, Timestamp};
+
 /// Data and progress events of the captured stream.
 #[derive(Debug, Clone, Abomonation, Hash, Ord, PartialOrd, Eq, PartialEq, Deserialize, Serialize)]
 pub enum Event<T, C> {
@@ -22,12 +24,78 @@ pub enum Event<T, C> {
 pub trait EventIterator<T, C> {
     /// Iterates over references to `Event<T, C>` elements.
     fn next(&mut self) -> Option<&Event<T, C>>;
+    /// Adapts the iterator to an `Iterator` over owned events.
+    ///
+    /// Each event is cloned, which `next` avoids; see `OwnedEvents` for details.
+    fn owned(self) -> OwnedEvents<Self> where Self: Sized {
+        OwnedEvents { iterator: self }
+    }
 }
 
 /// Receives `Event<T, C>` events.
 pub trait EventPusher<T, C> {
     /// Provides a new `Event<T, D>` to the pusher.
     fn push(&mut self, event: Event<T, C>);
+    /// Provides several events to the pusher, in order.
+    ///
+    /// The default implementation calls `push` for each event. Implementors may override it to
+    /// amortize per-event costs, for example by writing all events with a single write.
+    fn push_batch(&mut self, events: Vec<Event<T, C>>) {
+        for event in events {
+            self.push(event);
+        }
+    }
+}
+
+/// Receives `Event<T, C>` events, reporting failures to accept them.
+///
+/// Unlike `EventPusher`, implementors surface errors to the caller, who may decide to retry
+/// the event, to reconnect, or to give up.
+pub trait TryEventPusher<T, C> {
+    /// Attempts to provide a new `Event<T, C>` to the pusher.
+    fn try_push(&mut self, event: Event<T, C>) -> Result<(), EventError>;
+}
+
+/// Errors encountered while pushing or reading events.
+#[derive(Debug)]
+pub enum EventError {
+    /// The event could not be serialized or deserialized.
+    Serialization(String),
+    /// The underlying writer or reader reported an error.
+    Io(::std::io::Error),
+    /// A checksum did not match the event data it protects, indicating corruption.
+    Checksum {
+        /// The checksum recorded with the event.
+        expected: u32,
+        /// The checksum of the event data as read.
+        found: u32,
+    },
+}
+
+impl ::std::fmt::Display for EventError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            EventError::Serialization(message) => write!(f, "event serialization failed: {}", message),
+            EventError::Io(error) => write!(f, "event write failed: {}", error),
+            EventError::Checksum { expected, found } => write!(f, "event checksum mismatch: expected {:08x}, found {:08x}", expected, found),
+        }
+    }
+}
+
+impl ::std::error::Error for EventError {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            EventError::Serialization(_) => None,
+            EventError::Io(error) => Some(error),
+            EventError::Checksum { .. } => None,
+        }
+    }
+}
+
+impl From<::std::io::Error> for EventError {
+    fn from(error: ::std::io::Error) -> Self {
+        EventError::Io(error)
+    }
 }
 
 // implementation for the linked list behind a `Handle`.
@@ -39,6 +107,245 @@ impl<T, C> EventPusher<T, C> for ::std::sync::mpsc::Sender<Event<T, C>> {
     }
 }
 
+/// An event pusher that forwards each event to several downstream pushers.
+pub struct TeeEventPusher<T, C> {
+    pushers: Vec<Box<dyn EventPusher<T, C>>>,
+}
+
+impl<T, C> TeeEventPusher<T, C> {
+    /// Allocates a new `TeeEventPusher` forwarding to each of `pushers`.
+    pub fn new(pushers: Vec<Box<dyn EventPusher<T, C>>>) -> Self {
+        Self { pushers }
+    }
+    /// Adds a pusher to receive all subsequent events.
+    pub fn add<P: EventPusher<T, C>+'static>(&mut self, pusher: P) {
+        self.pushers.push(Box::new(pusher));
+    }
+}
+
+impl<T: Clone, C: Clone> EventPusher<T, C> for TeeEventPusher<T, C> {
+    fn push(&mut self, event: Event<T, C>) {
+        // clone the event for all but the last pusher, which receives the original.
+        if let Some((last, rest)) = self.pushers.split_last_mut() {
+            for pusher in rest.iter_mut() {
+                pusher.push(event.clone());
+            }
+            last.push(event);
+        }
+    }
+}
+
+/// An event pusher that forwards only those messages accepted by a predicate.
+///
+/// Progress events are always forwarded, without consulting the predicate, as discarding
+/// them breaks the protocol replay relies on, in that the replayed stream may never complete.
+pub struct FilterEventPusher<T, C, P, F> {
+    pusher: P,
+    predicate: F,
+    phant: ::std::marker::PhantomData<(T, C)>,
+}
+
+impl<T, C, P: EventPusher<T, C>, F: FnMut(&Event<T, C>)->bool> FilterEventPusher<T, C, P, F> {
+    /// Allocates a new `FilterEventPusher` forwarding events accepted by `predicate` to `pusher`.
+    pub fn new(pusher: P, predicate: F) -> Self {
+        Self {
+            pusher,
+            predicate,
+            phant: ::std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, C, P: EventPusher<T, C>, F: FnMut(&Event<T, C>)->bool> EventPusher<T, C> for FilterEventPusher<T, C, P, F> {
+    fn push(&mut self, event: Event<T, C>) {
+        if matches!(event, Event::Progress(_)) || (self.predicate)(&event) {
+            self.pusher.push(event);
+        }
+    }
+}
+
+/// An event pusher that transforms the containers of messages before forwarding them.
+///
+/// Progress events are forwarded unchanged.
+pub struct MapEventPusher<T, C, D, P, F> {
+    pusher: P,
+    logic: F,
+    phant: ::std::marker::PhantomData<(T, C, D)>,
+}
+
+impl<T, C, D, P: EventPusher<T, D>, F: FnMut(C)->D> MapEventPusher<T, C, D, P, F> {
+    /// Allocates a new `MapEventPusher` forwarding messages transformed by `logic` to `pusher`.
+    pub fn new(pusher: P, logic: F) -> Self {
+        Self {
+            pusher,
+            logic,
+            phant: ::std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, C, D, P: EventPusher<T, D>, F: FnMut(C)->D> EventPusher<T, C> for MapEventPusher<T, C, D, P, F> {
+    fn push(&mut self, event: Event<T, C>) {
+        match event {
+            Event::Progress(updates) => self.pusher.push(Event::Progress(updates)),
+            Event::Messages(time, data) => self.pusher.push(Event::Messages(time, (self.logic)(data))),
+        }
+    }
+}
+
+/// An event pusher that tags the container of each message with the index of a worker.
+///
+/// Messages are forwarded with containers `(worker_index, data)`, which allows captures from
+/// several workers to be merged while retaining the origin of each message. Progress events are
+/// forwarded unchanged, as replay accumulates progress from all workers alike.
+pub struct WorkerTaggingEventPusher<T, C, P: EventPusher<T, (usize, C)>> {
+    pusher: P,
+    worker_index: usize,
+    phant: ::std::marker::PhantomData<(T, C)>,
+}
+
+impl<T, C, P: EventPusher<T, (usize, C)>> WorkerTaggingEventPusher<T, C, P> {
+    /// Allocates a new `WorkerTaggingEventPusher` tagging messages with `worker_index`.
+    pub fn new(pusher: P, worker_index: usize) -> Self {
+        Self {
+            pusher,
+            worker_index,
+            phant: ::std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, C, P: EventPusher<T, (usize, C)>> EventPusher<T, C> for WorkerTaggingEventPusher<T, C, P> {
+    fn push(&mut self, event: Event<T, C>) {
+        match event {
+            Event::Progress(updates) => self.pusher.push(Event::Progress(updates)),
+            Event::Messages(time, data) => self.pusher.push(Event::Messages(time, (self.worker_index, data))),
+        }
+    }
+}
+
+/// An event iterator that strips the worker tags added by `WorkerTaggingEventPusher`.
+///
+/// Each event is cloned without its tag, and the worker that produced the most recent
+/// message is available from `last_worker`.
+pub struct WorkerTaggedEventIterator<T, C, I: EventIterator<T, (usize, C)>> {
+    iterator: I,
+    event: Option<Event<T, C>>,
+    last_worker: Option<usize>,
+}
+
+impl<T, C, I: EventIterator<T, (usize, C)>> WorkerTaggedEventIterator<T, C, I> {
+    /// Allocates a new `WorkerTaggedEventIterator` reading from `iterator`.
+    pub fn new(iterator: I) -> Self {
+        Self {
+            iterator,
+            event: None,
+            last_worker: None,
+        }
+    }
+    /// The index of the worker that produced the most recent message, if any.
+    pub fn last_worker(&self) -> Option<usize> {
+        self.last_worker
+    }
+}
+
+impl<T: Clone, C: Clone, I: EventIterator<T, (usize, C)>> EventIterator<T, C> for WorkerTaggedEventIterator<T, C, I> {
+    fn next(&mut self) -> Option<&Event<T, C>> {
+        let event = match self.iterator.next()? {
+            Event::Progress(updates) => Event::Progress(updates.clone()),
+            Event::Messages(time, (worker, data)) => {
+                self.last_worker = Some(*worker);
+                Event::Messages(time.clone(), data.clone())
+            },
+        };
+        self.event = Some(event);
+        self.event.as_ref()
+    }
+}
+
+/// An event pusher that coalesces consecutive progress events.
+///
+/// Progress updates are accumulated in a `ChangeBatch`, and forwarded as one compacted
+/// progress event when a message arrives, when the captured stream completes, when `flush`
+/// is called, or when dropped.
+pub struct CoalescingEventPusher<T: Timestamp, C, P: EventPusher<T, C>> {
+    pusher: P,
+    progress: ChangeBatch<T>,
+    /// The accumulated frontier of the captured stream, which completes when this is empty.
+    frontier: ChangeBatch<T>,
+    phant: ::std::marker::PhantomData<C>,
+}
+
+impl<T: Timestamp, C, P: EventPusher<T, C>> CoalescingEventPusher<T, C, P> {
+    /// Allocates a new `CoalescingEventPusher` forwarding to `pusher`.
+    pub fn new(pusher: P) -> Self {
+        Self {
+            pusher,
+            progress: ChangeBatch::new(),
+            // captured streams start from a single count of `T::minimum()`, as replay assumes.
+            frontier: ChangeBatch::new_from(T::minimum(), 1),
+            phant: ::std::marker::PhantomData,
+        }
+    }
+    /// Forwards any accumulated progress updates as a single progress event.
+    pub fn flush(&mut self) {
+        if !self.progress.is_empty() {
+            let updates = ::std::mem::replace(&mut self.progress, ChangeBatch::new());
+            self.pusher.push(Event::Progress(updates.into_inner()));
+        }
+    }
+}
+
+impl<T: Timestamp, C, P: EventPusher<T, C>> EventPusher<T, C> for CoalescingEventPusher<T, C, P> {
+    fn push(&mut self, event: Event<T, C>) {
+        match event {
+            Event::Progress(updates) => {
+                self.frontier.extend(updates.iter().cloned());
+                self.progress.extend(updates.into_iter());
+                // no further event may follow the completion of the stream to prompt a flush.
+                if self.frontier.is_empty() {
+                    self.flush();
+                }
+            },
+            Event::Messages(time, data) => {
+                // progress must not move earlier than messages, but may move later.
+                self.flush();
+                self.pusher.push(Event::Messages(time, data));
+            }
+        }
+    }
+}
+
+impl<T: Timestamp, C, P: EventPusher<T, C>> Drop for CoalescingEventPusher<T, C, P> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
+
+/// An `Iterator` over owned events, cloned from the references of an `EventIterator`.
+///
+/// The borrowed events of `EventIterator::next` can be replayed without copying, whereas this
+/// adapter clones each event, including its container, in exchange for the standard iterator
+/// combinators. The iterator ends when the inner iterator has no event available, which may
+/// only mean that no event is available yet; the adapter may be resumed once more arrive.
+pub struct OwnedEvents<I> {
+    iterator: I,
+}
+
+impl<I> OwnedEvents<I> {
+    /// Returns the wrapped event iterator.
+    pub fn into_inner(self) -> I {
+        self.iterator
+    }
+}
+
+impl<T: Clone, C: Clone, I: EventIterator<T, C>> Iterator for OwnedEvents<I> {
+    type Item = Event<T, C>;
+    fn next(&mut self) -> Option<Event<T, C>> {
+        self.iterator.next().cloned()
+    }
+}
+
 /// A linked-list event pusher and iterator.
 pub mod link {
 
@@ -48,6 +355,11 @@ pub mod link {
     use super::{Event, EventPusher, EventIterator};
 
     /// A linked list of Event<T, C>.
+    ///
+    /// An `Rc<EventLink<T, C>>` is both a pusher, appending to the list, and an iterator, reading
+    /// the events following its position. Each clone of an `Rc<EventLink<T, C>>` is an independent
+    /// cursor, so cloning the head of a list before events are pushed gives each consumer the full
+    /// history of the capture. Events are retained as long as some clone has yet to read them.
     pub struct EventLink<T, C> {
         /// An event, if one exists.
         ///
@@ -105,6 +417,139 @@ pub mod link {
         }
     }
 
+    #[test]
+    fn worker_tags() {
+        use super::{WorkerTaggingEventPusher, WorkerTaggedEventIterator};
+        let link = Rc::new(EventLink::<u64, (usize, Vec<u64>)>::new());
+        let mut reader = WorkerTaggedEventIterator::new(link.clone());
+        let mut writer0 = WorkerTaggingEventPusher::new(link.clone(), 0);
+        writer0.push(Event::Messages(0, vec![0]));
+        let mut writer1 = WorkerTaggingEventPusher::new(writer0.pusher.clone(), 1);
+        writer1.push(Event::Messages(0, vec![1]));
+        assert_eq!(reader.next(), Some(&Event::Messages(0, vec![0])));
+        assert_eq!(reader.last_worker(), Some(0));
+        assert_eq!(reader.next(), Some(&Event::Messages(0, vec![1])));
+        assert_eq!(reader.last_worker(), Some(1));
+    }
+
+    #[test]
+    fn tee_forwards_to_all() {
+        use super::TeeEventPusher;
+        let link1 = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let link2 = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let mut reader1 = link1.clone();
+        let mut reader2 = link2.clone();
+        let mut writer = TeeEventPusher::<u64, Vec<u64>>::new(Vec::new());
+        writer.add(link1);
+        writer.add(link2);
+        writer.push(Event::Messages(0, vec![0]));
+        writer.push(Event::Progress(vec![(0, -1), (1, 1)]));
+        writer.push(Event::Messages(1, vec![1]));
+        for reader in [&mut reader1, &mut reader2] {
+            assert_eq!(reader.next(), Some(&Event::Messages(0, vec![0])));
+            assert_eq!(reader.next(), Some(&Event::Progress(vec![(0, -1), (1, 1)])));
+            assert_eq!(reader.next(), Some(&Event::Messages(1, vec![1])));
+            assert_eq!(reader.next(), None);
+        }
+    }
+
+    #[test]
+    fn filter_keeps_progress() {
+        use super::FilterEventPusher;
+        let link = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let mut reader = link.clone();
+        let mut writer = FilterEventPusher::new(link, |event: &Event<u64, Vec<u64>>| match event {
+            Event::Messages(time, _) => *time > 0,
+            Event::Progress(_) => false,
+        });
+        writer.push(Event::Messages(0, vec![0]));
+        writer.push(Event::Progress(vec![(0, -1), (1, 1)]));
+        writer.push(Event::Messages(1, vec![1]));
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(0, -1), (1, 1)])));
+        assert_eq!(reader.next(), Some(&Event::Messages(1, vec![1])));
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn map_transforms_messages() {
+        use super::MapEventPusher;
+        let link = Rc::new(EventLink::<u64, usize>::new());
+        let mut reader = link.clone();
+        let mut writer = MapEventPusher::new(link, |data: Vec<u64>| data.len());
+        writer.push(Event::Messages(0, vec![0, 1, 2]));
+        writer.push(Event::Progress(vec![(0, -1), (1, 1)]));
+        assert_eq!(reader.next(), Some(&Event::Messages(0, 3)));
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(0, -1), (1, 1)])));
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn coalescing_progress() {
+        use super::CoalescingEventPusher;
+        let link = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let mut reader = link.clone();
+        let mut writer = CoalescingEventPusher::new(link);
+        writer.push(Event::Progress(vec![(0, -1), (1, 1)]));
+        writer.push(Event::Progress(vec![(1, -1), (2, 1)]));
+        assert_eq!(reader.next(), None);
+        writer.flush();
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(0, -1), (2, 1)])));
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn coalescing_flushes_before_messages() {
+        use super::CoalescingEventPusher;
+        let link = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let mut reader = link.clone();
+        let mut writer = CoalescingEventPusher::new(link);
+        writer.push(Event::Progress(vec![(0, -1), (1, 1)]));
+        writer.push(Event::Messages(1, vec![1]));
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(0, -1), (1, 1)])));
+        assert_eq!(reader.next(), Some(&Event::Messages(1, vec![1])));
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn coalescing_flushes_at_completion() {
+        use super::CoalescingEventPusher;
+        let link = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let mut reader = link.clone();
+        let mut writer = CoalescingEventPusher::new(link);
+        writer.push(Event::Progress(vec![(0, -1), (1, 1)]));
+        writer.push(Event::Progress(vec![(1, -1)]));
+        // the frontier is empty, so progress is forwarded without a flush or drop.
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(0, -1)])));
+        assert_eq!(reader.next(), None);
+        drop(writer);
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn independent_readers() {
+        let mut writer = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let mut reader1 = writer.clone();
+        writer.push(Event::Messages(0, vec![0]));
+        assert_eq!(reader1.next(), Some(&Event::Messages(0, vec![0])));
+        let mut reader2 = writer.clone();
+        let mut reader3 = reader1.clone();
+        writer.push(Event::Messages(0, vec![1]));
+        for reader in [&mut reader1, &mut reader2, &mut reader3] {
+            assert_eq!(reader.next(), Some(&Event::Messages(0, vec![1])));
+            assert_eq!(reader.next(), None);
+        }
+    }
+
+    #[test]
+    fn owned_events() {
+        let mut writer = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let reader = writer.clone();
+        writer.push(Event::Messages(0, vec![0, 1]));
+        writer.push(Event::Progress(vec![(0, -1)]));
+        let events = reader.owned().collect::<Vec<_>>();
+        assert_eq!(events, vec![Event::Messages(0, vec![0, 1]), Event::Progress(vec![(0, -1)])]);
+    }
+
     #[test]
     fn avoid_stack_overflow_in_drop() {
         let mut event1 = Rc::new(EventLink::<(),()>::new());
@@ -115,86 +560,3200 @@ pub mod link {
     }
 }
 
+/// Event pushers and iterators for testing.
+pub mod testing {
+
+    use super::{Event, EventPusher, EventIterator};
+
+    /// An `EventPusher` which discards every event.
+    pub struct NullEventPusher<T, C> {
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    impl<T, C> NullEventPusher<T, C> {
+        /// Allocates a new `NullEventPusher`.
+        pub fn new() -> Self {
+            NullEventPusher { phant: ::std::marker::PhantomData }
+        }
+    }
+
+    impl<T, C> Default for NullEventPusher<T, C> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T, C> EventPusher<T, C> for NullEventPusher<T, C> {
+        fn push(&mut self, _event: Event<T, C>) { }
+    }
+
+    /// An `EventIterator` which yields a fixed list of events in order, and then `None`.
+    pub struct ScriptedEventIterator<T, C> {
+        events: Vec<Event<T, C>>,
+        position: usize,
+    }
+
+    impl<T, C> ScriptedEventIterator<T, C> {
+        /// Allocates a new `ScriptedEventIterator` yielding `events`.
+        pub fn new(events: Vec<Event<T, C>>) -> Self {
+            ScriptedEventIterator { events, position: 0 }
+        }
+        /// True iff every event has been yielded.
+        pub fn done(&self) -> bool {
+            self.position >= self.events.len()
+        }
+    }
+
+    impl<T, C> EventIterator<T, C> for ScriptedEventIterator<T, C> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            let event = self.events.get(self.position);
+            if event.is_some() {
+                self.position += 1;
+            }
+            event
+        }
+    }
+
+    #[test]
+    fn scripted() {
+        let mut iterator = ScriptedEventIterator::new(vec![Event::Messages(0u64, vec![0u64]), Event::Progress(vec![(0, -1)])]);
+        let mut pusher = NullEventPusher::new();
+        while let Some(event) = iterator.next() {
+            pusher.push(event.clone());
+        }
+        assert!(iterator.done());
+        assert_eq!(iterator.next(), None);
+    }
+}
+
 /// A binary event pusher and iterator.
+///
+/// Events are serialized with abomonation by default. Other serialization formats can be
+/// used by supplying an `EventCodec`, for example to read a capture from another language.
 pub mod binary {
 
     use std::io::Write;
     use abomonation::Abomonation;
-    use super::{Event, EventPusher, EventIterator};
+    use super::{Event, EventPusher, EventIterator, TryEventPusher, EventError};
+
+    /// Serializes and deserializes events for `EventWriter` and `EventReader`.
+    ///
+    /// Deserialization happens in two steps, so that readers can decide whether to return an
+    /// event before borrowing it: `decode` prepares an event and reports its length, after which
+    /// `event` presents the event decoded from the same bytes.
+    pub trait EventCodec<T, C> {
+        /// Serializes `event` onto the end of `bytes`.
+        fn serialize_into(&mut self, event: &Event<T, C>, bytes: &mut Vec<u8>) -> Result<(), EventError>;
+        /// Decodes an event from the start of `bytes`, returning the number of bytes it occupies.
+        ///
+        /// Returns `None` if `bytes` do not start with a complete event.
+        fn decode(&mut self, bytes: &mut [u8]) -> Option<usize>;
+        /// Presents the event most recently decoded by `decode` from the start of `bytes`.
+        fn event<'a>(&'a mut self, bytes: &'a mut [u8]) -> &'a Event<T, C>;
+    }
+
+    /// An `EventCodec` using abomonation, whose events are read in place without copying.
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct AbomonationCodec;
+
+    impl<T: Abomonation, C: Abomonation> EventCodec<T, C> for AbomonationCodec {
+        fn serialize_into(&mut self, event: &Event<T, C>, bytes: &mut Vec<u8>) -> Result<(), EventError> {
+            unsafe { ::abomonation::encode(event, bytes) }
+                .map_err(|error| EventError::Serialization(error.to_string()))
+        }
+        fn decode(&mut self, bytes: &mut [u8]) -> Option<usize> {
+            let length = bytes.len();
+            unsafe { ::abomonation::decode::<Event<T, C>>(bytes) }.map(|(_, rest)| length - rest.len())
+        }
+        fn event<'a>(&'a mut self, bytes: &'a mut [u8]) -> &'a Event<T, C> {
+            unsafe { ::abomonation::decode::<Event<T, C>>(bytes) }.expect("event previously decoded").0
+        }
+    }
+
+    /// An `EventCodec` using bincode.
+    #[cfg(feature = "bincode")]
+    pub struct BincodeCodec<T, C> {
+        event: Option<Event<T, C>>,
+    }
+
+    #[cfg(feature = "bincode")]
+    impl<T, C> Default for BincodeCodec<T, C> {
+        fn default() -> Self {
+            BincodeCodec { event: None }
+        }
+    }
+
+    #[cfg(feature = "bincode")]
+    impl<T, C> EventCodec<T, C> for BincodeCodec<T, C>
+    where
+        T: ::serde::Serialize + ::serde::de::DeserializeOwned,
+        C: ::serde::Serialize + ::serde::de::DeserializeOwned,
+    {
+        fn serialize_into(&mut self, event: &Event<T, C>, bytes: &mut Vec<u8>) -> Result<(), EventError> {
+            ::bincode_dep::serialize_into(bytes, event)
+                .map_err(|error| EventError::Serialization(error.to_string()))
+        }
+        fn decode(&mut self, bytes: &mut [u8]) -> Option<usize> {
+            let mut slice: &[u8] = bytes;
+            // An error may indicate an incomplete event, which we cannot distinguish from corruption.
+            self.event = ::bincode_dep::deserialize_from(&mut slice).ok();
+            self.event.as_ref().map(|_| bytes.len() - slice.len())
+        }
+        fn event<'a>(&'a mut self, _bytes: &'a mut [u8]) -> &'a Event<T, C> {
+            self.event.as_ref().expect("event previously decoded")
+        }
+    }
+
+    /// An `EventCodec` using postcard, a compact format with implementations in other languages.
+    #[cfg(feature = "postcard")]
+    pub struct PostcardCodec<T, C> {
+        event: Option<Event<T, C>>,
+    }
+
+    #[cfg(feature = "postcard")]
+    impl<T, C> Default for PostcardCodec<T, C> {
+        fn default() -> Self {
+            PostcardCodec { event: None }
+        }
+    }
+
+    #[cfg(feature = "postcard")]
+    impl<T, C> EventCodec<T, C> for PostcardCodec<T, C>
+    where
+        T: ::serde::Serialize + ::serde::de::DeserializeOwned,
+        C: ::serde::Serialize + ::serde::de::DeserializeOwned,
+    {
+        fn serialize_into(&mut self, event: &Event<T, C>, bytes: &mut Vec<u8>) -> Result<(), EventError> {
+            let serialized = ::postcard::to_allocvec(event)
+                .map_err(|error| EventError::Serialization(error.to_string()))?;
+            bytes.extend_from_slice(&serialized[..]);
+            Ok(())
+        }
+        fn decode(&mut self, bytes: &mut [u8]) -> Option<usize> {
+            // An error may indicate an incomplete event, which we cannot distinguish from corruption.
+            match ::postcard::take_from_bytes::<Event<T, C>>(bytes) {
+                Ok((event, rest)) => {
+                    let length = bytes.len() - rest.len();
+                    self.event = Some(event);
+                    Some(length)
+                },
+                Err(_) => None,
+            }
+        }
+        fn event<'a>(&'a mut self, _bytes: &'a mut [u8]) -> &'a Event<T, C> {
+            self.event.as_ref().expect("event previously decoded")
+        }
+    }
+
+    /// The length in bytes of the header preceding each checksummed event.
+    ///
+    /// The header is the length of the event as a little-endian `u64`, followed by the CRC32 of
+    /// the length and the event as a little-endian `u32`, and four bytes of padding so that the
+    /// event remains aligned to eight bytes, as abomonation requires. The event is followed by
+    /// zeros up to a multiple of eight bytes, which the length excludes, so that the next event
+    /// is aligned as well.
+    const CHECKSUM_HEADER: usize = 16;
+
+    /// Rounds `length` up to a multiple of eight bytes, the extent of a checksummed event.
+    fn padded(length: usize) -> usize {
+        length.saturating_add(7) & !7
+    }
+
+    /// The length in bytes of the header preceding each timed event.
+    ///
+    /// The header is the time elapsed since the previous event was written, in nanoseconds,
+    /// as a little-endian `u64`. It precedes the checksum header, if there is one.
+    const TIMING_HEADER: usize = 8;
+
+    /// Computes the CRC32 (IEEE) checksum of the concatenation of `chunks`.
+    fn crc32(chunks: &[&[u8]]) -> u32 {
+        let mut crc = !0u32;
+        for byte in chunks.iter().flat_map(|chunk| chunk.iter()) {
+            crc ^= *byte as u32;
+            for _ in 0 .. 8 {
+                let mask = (crc & 1).wrapping_neg();
+                crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+            }
+        }
+        !crc
+    }
 
     /// A wrapper for `W: Write` implementing `EventPusher<T, C>`.
-    pub struct EventWriter<T, C, W: ::std::io::Write> {
+    pub struct EventWriter<T, C, W: ::std::io::Write, K = AbomonationCodec> {
         stream: W,
+        buffer: Vec<u8>,
+        checksum: bool,
+        timed: bool,
+        last: Option<::std::time::Instant>,
+        codec: K,
         phant: ::std::marker::PhantomData<(T, C)>,
     }
 
     impl<T, C, W: ::std::io::Write> EventWriter<T, C, W> {
         /// Allocates a new `EventWriter` wrapping a supplied writer.
         pub fn new(w: W) -> Self {
+            Self::with_codec(w, AbomonationCodec)
+        }
+        /// Allocates a new `EventWriter` which precedes each event with its length and checksum.
+        ///
+        /// The output can only be read by an `EventReader` constructed with `with_checksum`.
+        pub fn with_checksum(w: W) -> Self {
+            let mut writer = Self::new(w);
+            writer.checksum = true;
+            writer
+        }
+    }
+
+    impl<T, C, W: ::std::io::Write, K> EventWriter<T, C, W, K> {
+        /// Allocates a new `EventWriter` serializing events with `codec`.
+        pub fn with_codec(w: W, codec: K) -> Self {
             Self {
                 stream: w,
+                buffer: Vec::new(),
+                checksum: false,
+                timed: false,
+                last: None,
+                codec,
                 phant: ::std::marker::PhantomData,
             }
         }
+        /// Precedes each event with its length and checksum, as for `with_checksum`.
+        pub fn checksummed(mut self) -> Self {
+            self.checksum = true;
+            self
+        }
+        /// Precedes each event with the time elapsed since the previous event was written.
+        ///
+        /// The output can only be read by an `EventReader` constructed with `timed`, for example
+        /// by a `TimedReplayIterator` reproducing the original gaps between events.
+        pub fn timed(mut self) -> Self {
+            self.timed = true;
+            self
+        }
+        /// Flushes the underlying writer.
+        pub fn flush(&mut self) -> ::std::io::Result<()> {
+            self.stream.flush()
+        }
+        /// Flushes the underlying writer and consumes the `EventWriter`.
+        ///
+        /// The writer is also flushed when dropped, but errors cannot be reported from `drop`.
+        /// Callers should prefer `close` in order to observe them.
+        pub fn close(mut self) -> ::std::io::Result<()> {
+            self.flush()
+        }
     }
 
-    impl<T: Abomonation, C: Abomonation, W: ::std::io::Write> EventPusher<T, C> for EventWriter<T, C, W> {
-        fn push(&mut self, event: Event<T, C>) {
-            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
-            unsafe { ::abomonation::encode(&event, &mut self.stream).expect("Event abomonation/write failed"); }
+    impl<T, C, W: ::std::io::Write, K> Drop for EventWriter<T, C, W, K> {
+        fn drop(&mut self) {
+            // Errors cannot be reported here; call `close` first to observe them.
+            let _ = self.stream.flush();
         }
     }
 
-    /// A Wrapper for `R: Read` implementing `EventIterator<T, D>`.
-    pub struct EventReader<T, C, R: ::std::io::Read> {
-        reader: R,
-        bytes: Vec<u8>,
-        buff1: Vec<u8>,
-        buff2: Vec<u8>,
-        consumed: usize,
-        valid: usize,
-        phant: ::std::marker::PhantomData<(T, C)>,
+    impl<T, C, W: ::std::io::Write, K: EventCodec<T, C>> TryEventPusher<T, C> for EventWriter<T, C, W, K> {
+        fn try_push(&mut self, event: Event<T, C>) -> Result<(), EventError> {
+            // Serialize completely before writing, to distinguish serialization errors from write errors.
+            self.buffer.clear();
+            self.serialize(&event)?;
+            self.stream.write_all(&self.buffer[..])?;
+            Ok(())
+        }
     }
 
-    impl<T, C, R: ::std::io::Read> EventReader<T, C, R> {
-        /// Allocates a new `EventReader` wrapping a supplied reader.
-        pub fn new(r: R) -> Self {
-            Self {
-                reader: r,
-                bytes: vec![0u8; 1 << 20],
-                buff1: vec![],
-                buff2: vec![],
-                consumed: 0,
-                valid: 0,
-                phant: ::std::marker::PhantomData,
+    impl<T, C, W: ::std::io::Write, K: EventCodec<T, C>> EventWriter<T, C, W, K> {
+        /// Serializes `event`, with any headers, onto the end of `self.buffer`.
+        fn serialize(&mut self, event: &Event<T, C>) -> Result<(), EventError> {
+            let start = self.buffer.len();
+            let prefix = if self.timed { start + TIMING_HEADER } else { start };
+            let header = if self.checksum { prefix + CHECKSUM_HEADER } else { prefix };
+            self.buffer.resize(header, 0);
+            if let Err(error) = self.codec.serialize_into(event, &mut self.buffer) {
+                self.buffer.truncate(start);
+                return Err(error);
             }
+            if self.timed {
+                let now = ::std::time::Instant::now();
+                let delay = self.last.map(|last| now.duration_since(last).as_nanos() as u64).unwrap_or(0);
+                self.last = Some(now);
+                self.buffer[start .. prefix].copy_from_slice(&delay.to_le_bytes());
+            }
+            if self.checksum {
+                let length = ((self.buffer.len() - header) as u64).to_le_bytes();
+                let crc = crc32(&[&length[..], &self.buffer[header..]]);
+                self.buffer[prefix .. prefix + 8].copy_from_slice(&length);
+                self.buffer[prefix + 8 .. prefix + 12].copy_from_slice(&crc.to_le_bytes());
+                let extent = header + padded(self.buffer.len() - header);
+                self.buffer.resize(extent, 0);
+            }
+            Ok(())
         }
     }
 
-    impl<T: Abomonation, C: Abomonation, R: ::std::io::Read> EventIterator<T, C> for EventReader<T, C, R> {
-        fn next(&mut self) -> Option<&Event<T, C>> {
-
-            // if we can decode something, we should just return it! :D
-            if unsafe { ::abomonation::decode::<Event<T, C>>(&mut self.buff1[self.consumed..]) }.is_some() {
-                let (item, rest) = unsafe { ::abomonation::decode::<Event<T, C>>(&mut self.buff1[self.consumed..]) }.unwrap();
-                self.consumed = self.valid - rest.len();
-                return Some(item);
+    impl<T, C, W: ::std::io::Write, K: EventCodec<T, C>> EventPusher<T, C> for EventWriter<T, C, W, K> {
+        fn push(&mut self, event: Event<T, C>) {
+            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
+            self.try_push(event).expect("Event serialization/write failed");
+        }
+        fn push_batch(&mut self, events: Vec<Event<T, C>>) {
+            // Serialize all events into one buffer, and write them with a single call.
+            self.buffer.clear();
+            for event in events.iter() {
+                self.serialize(event).expect("Event serialization failed");
             }
-            // if we exhaust data we should shift back (if any shifting to do)
-            if self.consumed > 0 {
-                self.buff2.clear();
-                self.buff2.write_all(&self.buff1[self.consumed..]).unwrap();
+            self.stream.write_all(&self.buffer[..]).expect("Event write failed");
+        }
+    }
+
+    /// When a `RotatingEventWriter` moves on to a new file.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum RotationPolicy {
+        /// Once a file holds at least this many bytes.
+        Bytes(usize),
+        /// Once a file holds this many events.
+        Events(usize),
+    }
+
+    /// The path of the file with sequence number `index`, for files rotated from `base`.
+    fn rotated_path(base: &::std::path::Path, index: usize) -> ::std::path::PathBuf {
+        let mut name = base.as_os_str().to_owned();
+        name.push(format!(".{:06}", index));
+        name.into()
+    }
+
+    /// An `EventPusher` writing events to a sequence of files, starting a new file as each fills.
+    ///
+    /// Files are named by appending a six digit sequence number to a base path, as `capture.000000`,
+    /// `capture.000001`, and so on. Each file is flushed and closed before the next is created, and so
+    /// the existence of a file indicates that all earlier files are complete. A `RotatingEventReader`
+    /// with the same base path replays the files in order.
+    pub struct RotatingEventWriter<T, C, K = AbomonationCodec> {
+        base: ::std::path::PathBuf,
+        policy: RotationPolicy,
+        index: usize,
+        bytes: usize,
+        events: usize,
+        writer: EventWriter<T, C, ::std::io::BufWriter<::std::fs::File>, K>,
+    }
+
+    impl<T, C, K: Default> RotatingEventWriter<T, C, K> {
+        /// Creates the first file for `base`, rotating files according to `policy`.
+        pub fn new<P: Into<::std::path::PathBuf>>(base: P, policy: RotationPolicy) -> ::std::io::Result<Self> {
+            let base = base.into();
+            let file = ::std::fs::File::create(rotated_path(&base, 0))?;
+            Ok(Self {
+                base,
+                policy,
+                index: 0,
+                bytes: 0,
+                events: 0,
+                writer: EventWriter::with_codec(::std::io::BufWriter::new(file), K::default()),
+            })
+        }
+        /// The path of the file currently being written.
+        pub fn current_path(&self) -> ::std::path::PathBuf {
+            rotated_path(&self.base, self.index)
+        }
+        /// Completes the current file, and starts the next.
+        pub fn rotate(&mut self) -> ::std::io::Result<()> {
+            // complete the current file before creating the next, which signals its completion.
+            self.writer.flush()?;
+            let file = ::std::fs::File::create(rotated_path(&self.base, self.index + 1))?;
+            self.writer = EventWriter::with_codec(::std::io::BufWriter::new(file), K::default());
+            self.index += 1;
+            self.bytes = 0;
+            self.events = 0;
+            Ok(())
+        }
+        /// Flushes the current file.
+        pub fn flush(&mut self) -> ::std::io::Result<()> {
+            self.writer.flush()
+        }
+    }
+
+    impl<T, C, K: EventCodec<T, C> + Default> TryEventPusher<T, C> for RotatingEventWriter<T, C, K> {
+        fn try_push(&mut self, event: Event<T, C>) -> Result<(), EventError> {
+            self.writer.try_push(event)?;
+            // the writer serializes each event into its buffer before writing it.
+            self.bytes += self.writer.buffer.len();
+            self.events += 1;
+            let full = match self.policy {
+                RotationPolicy::Bytes(bytes) => self.bytes >= bytes,
+                RotationPolicy::Events(events) => self.events >= events,
+            };
+            if full {
+                self.rotate()?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<T, C, K: EventCodec<T, C> + Default> EventPusher<T, C> for RotatingEventWriter<T, C, K> {
+        fn push(&mut self, event: Event<T, C>) {
+            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
+            self.try_push(event).expect("Event serialization/write/rotation failed");
+        }
+    }
+
+    /// An `EventIterator` reading the files written by a `RotatingEventWriter`, in order.
+    ///
+    /// The reader moves on to the next file once it exists and the current file is exhausted, and
+    /// so can follow a capture as it is written.
+    pub struct RotatingEventReader<T, C, K = AbomonationCodec> {
+        base: ::std::path::PathBuf,
+        index: usize,
+        /// Set once the next file has been seen, after which the end of the current file is final.
+        next_exists: bool,
+        reader: EventReader<T, C, ::std::fs::File, K>,
+    }
+
+    impl<T, C, K: Default> RotatingEventReader<T, C, K> {
+        /// Opens the first file for `base`.
+        pub fn open<P: Into<::std::path::PathBuf>>(base: P) -> ::std::io::Result<Self> {
+            let base = base.into();
+            let file = ::std::fs::File::open(rotated_path(&base, 0))?;
+            Ok(Self {
+                base,
+                index: 0,
+                next_exists: false,
+                reader: EventReader::with_codec(file, K::default()),
+            })
+        }
+        /// The path of the file currently being read.
+        pub fn current_path(&self) -> ::std::path::PathBuf {
+            rotated_path(&self.base, self.index)
+        }
+    }
+
+    impl<T, C, K: EventCodec<T, C> + Default> EventIterator<T, C> for RotatingEventReader<T, C, K> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            loop {
+                if let Some((start, end)) = self.reader.step() {
+                    return Some(self.reader.codec.event(&mut self.reader.buff1[start .. end]));
+                }
+                let exhausted = self.reader.at_end && self.reader.consumed == self.reader.valid;
+                if !exhausted || self.reader.error.is_some() {
+                    return None;
+                }
+                if self.next_exists {
+                    // the current file was complete when last read to its end; move to the next.
+                    let file = ::std::fs::File::open(rotated_path(&self.base, self.index + 1)).ok()?;
+                    self.reader = EventReader::with_codec(file, K::default());
+                    self.index += 1;
+                    self.next_exists = false;
+                }
+                else if rotated_path(&self.base, self.index + 1).exists() {
+                    // read the current file to its end once more, as it may have been completed meanwhile.
+                    self.next_exists = true;
+                }
+                else {
+                    return None;
+                }
+            }
+        }
+    }
+
+    /// A shared histogram of the serialized sizes of events written by a `SizingEventWriter`.
+    #[derive(Clone, Debug, Default)]
+    pub struct EventSizes {
+        histogram: ::std::rc::Rc<::std::cell::RefCell<::std::collections::BTreeMap<usize, usize>>>,
+    }
+
+    impl EventSizes {
+        /// Allocates a new empty histogram.
+        pub fn new() -> Self {
+            Self::default()
+        }
+        /// Returns pairs of serialized size in bytes and the number of events of that size,
+        /// in increasing order of size.
+        pub fn histogram(&self) -> Vec<(usize, usize)> {
+            self.histogram.borrow().iter().map(|(size, count)| (*size, *count)).collect()
+        }
+        /// Returns the number of events written.
+        pub fn events(&self) -> usize {
+            self.histogram.borrow().values().sum()
+        }
+        /// Returns the number of bytes written, as the sum of all event sizes.
+        pub fn bytes(&self) -> usize {
+            self.histogram.borrow().iter().map(|(size, count)| size * count).sum()
+        }
+        /// Discards all observations.
+        pub fn clear(&self) {
+            self.histogram.borrow_mut().clear();
+        }
+    }
+
+    /// An `EventWriter` which records the serialized size of each event it writes.
+    ///
+    /// Sizes include any headers the writer adds, and so are the numbers of bytes written to `W`.
+    /// The distribution can be used to estimate the bandwidth a remote capture would require.
+    pub struct SizingEventWriter<T, C, W: ::std::io::Write, K = AbomonationCodec> {
+        writer: EventWriter<T, C, W, K>,
+        sizes: EventSizes,
+    }
+
+    impl<T, C, W: ::std::io::Write, K> SizingEventWriter<T, C, W, K> {
+        /// Wraps `writer`, recording the size of each event it writes into `sizes`.
+        pub fn new(writer: EventWriter<T, C, W, K>, sizes: &EventSizes) -> Self {
+            Self {
+                writer,
+                sizes: sizes.clone(),
+            }
+        }
+        /// The histogram of event sizes.
+        pub fn sizes(&self) -> &EventSizes {
+            &self.sizes
+        }
+        /// Unwraps the underlying `EventWriter`.
+        pub fn into_inner(self) -> EventWriter<T, C, W, K> {
+            self.writer
+        }
+    }
+
+    impl<T, C, W: ::std::io::Write, K: EventCodec<T, C>> TryEventPusher<T, C> for SizingEventWriter<T, C, W, K> {
+        fn try_push(&mut self, event: Event<T, C>) -> Result<(), EventError> {
+            self.writer.try_push(event)?;
+            // the writer serializes each event into its buffer before writing it.
+            *self.sizes.histogram.borrow_mut().entry(self.writer.buffer.len()).or_insert(0) += 1;
+            Ok(())
+        }
+    }
+
+    impl<T, C, W: ::std::io::Write, K: EventCodec<T, C>> EventPusher<T, C> for SizingEventWriter<T, C, W, K> {
+        fn push(&mut self, event: Event<T, C>) {
+            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
+            self.try_push(event).expect("Event serialization/write failed");
+        }
+    }
+
+    /// When a `DurableEventWriter` synchronizes its file with the storage device.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum SyncPolicy {
+        /// Only when `sync` is called.
+        Manual,
+        /// After every `n` events.
+        Events(usize),
+        /// After every `Event::Progress`, so that synchronized files end at a progress boundary.
+        Progress,
+    }
+
+    /// An `EventWriter` to a `File`, which controls when written events reach the storage device.
+    ///
+    /// Events are buffered in memory and written to the file on `sync`, which also waits for the
+    /// operating system to persist the file with `File::sync_all`. Events pushed before a sync
+    /// that completes will be read back after a crash, so that replay observes a prefix of the
+    /// captured events. With `SyncPolicy::Progress` the prefix ends at a progress event.
+    ///
+    /// Each sync blocks until the device acknowledges the write, which commonly takes between
+    /// milliseconds and tens of milliseconds. Frequent syncs, for example after every event,
+    /// can reduce throughput by orders of magnitude; prefer syncing at progress boundaries or
+    /// every few thousand events where the workload allows.
+    pub struct DurableEventWriter<T, C, K = AbomonationCodec> {
+        writer: EventWriter<T, C, ::std::io::BufWriter<::std::fs::File>, K>,
+        policy: SyncPolicy,
+        unsynced: usize,
+    }
+
+    impl<T, C> DurableEventWriter<T, C> {
+        /// Allocates a new `DurableEventWriter` wrapping `file`, syncing only when asked.
+        pub fn new(file: ::std::fs::File) -> Self {
+            Self::with_codec(file, AbomonationCodec)
+        }
+    }
+
+    impl<T, C, K> DurableEventWriter<T, C, K> {
+        /// Allocates a new `DurableEventWriter` serializing events with `codec`.
+        pub fn with_codec(file: ::std::fs::File, codec: K) -> Self {
+            Self {
+                writer: EventWriter::with_codec(::std::io::BufWriter::new(file), codec),
+                policy: SyncPolicy::Manual,
+                unsynced: 0,
+            }
+        }
+        /// Synchronizes the file automatically according to `policy`.
+        pub fn sync_policy(mut self, policy: SyncPolicy) -> Self {
+            self.policy = policy;
+            self
+        }
+        /// Writes buffered events to the file, and waits for the file to reach the storage device.
+        pub fn sync(&mut self) -> ::std::io::Result<()> {
+            self.writer.flush()?;
+            self.writer.stream.get_ref().sync_all()?;
+            self.unsynced = 0;
+            Ok(())
+        }
+        /// The number of events pushed since the last sync.
+        pub fn unsynced(&self) -> usize {
+            self.unsynced
+        }
+    }
+
+    impl<T, C, K> Drop for DurableEventWriter<T, C, K> {
+        fn drop(&mut self) {
+            // Errors cannot be reported here; call `sync` first to observe them.
+            if self.unsynced > 0 {
+                let _ = self.sync();
+            }
+        }
+    }
+
+    impl<T, C, K: EventCodec<T, C>> TryEventPusher<T, C> for DurableEventWriter<T, C, K> {
+        fn try_push(&mut self, event: Event<T, C>) -> Result<(), EventError> {
+            let progress = matches!(event, Event::Progress(_));
+            self.writer.try_push(event)?;
+            self.unsynced += 1;
+            let sync = match self.policy {
+                SyncPolicy::Manual => false,
+                SyncPolicy::Events(n) => self.unsynced >= n,
+                SyncPolicy::Progress => progress,
+            };
+            if sync {
+                self.sync()?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<T, C, K: EventCodec<T, C>> EventPusher<T, C> for DurableEventWriter<T, C, K> {
+        fn push(&mut self, event: Event<T, C>) {
+            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
+            self.try_push(event).expect("Event write/sync failed");
+        }
+    }
+
+    /// A Wrapper for `R: Read` implementing `EventIterator<T, D>`.
+    pub struct EventReader<T, C, R: ::std::io::Read, K = AbomonationCodec> {
+        reader: R,
+        bytes: Vec<u8>,
+        buff1: Vec<u8>,
+        buff2: Vec<u8>,
+        consumed: usize,
+        valid: usize,
+        checksum: bool,
+        timed: bool,
+        at_end: bool,
+        error: Option<EventError>,
+        codec: K,
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    impl<T, C, R: ::std::io::Read> EventReader<T, C, R> {
+        /// Allocates a new `EventReader` wrapping a supplied reader.
+        pub fn new(r: R) -> Self {
+            Self::with_codec(r, AbomonationCodec)
+        }
+        /// Allocates a new `EventReader` for data written by `EventWriter::with_checksum`.
+        ///
+        /// Each event is verified against its checksum before it is returned. On a mismatch the
+        /// reader stops producing events, and the error is available from `error`.
+        pub fn with_checksum(r: R) -> Self {
+            Self {
+                checksum: true,
+                ..Self::new(r)
+            }
+        }
+    }
+
+    impl<T, C, R: ::std::io::Read, K> EventReader<T, C, R, K> {
+        /// Allocates a new `EventReader` deserializing events with `codec`.
+        pub fn with_codec(r: R, codec: K) -> Self {
+            Self {
+                reader: r,
+                bytes: vec![0u8; 1 << 20],
+                buff1: vec![],
+                buff2: vec![],
+                consumed: 0,
+                valid: 0,
+                checksum: false,
+                timed: false,
+                at_end: false,
+                error: None,
+                codec,
+                phant: ::std::marker::PhantomData,
+            }
+        }
+        /// Expects each event to be preceded by its length and checksum, as for `with_checksum`.
+        pub fn checksummed(mut self) -> Self {
+            self.checksum = true;
+            self
+        }
+        /// Expects each event to be preceded by the time elapsed since the previous event, as for `EventWriter::timed`.
+        pub fn timed(mut self) -> Self {
+            self.timed = true;
+            self
+        }
+        /// The recorded delay before the next event, if the reader is timed and has read it.
+        ///
+        /// The delay is available once the header of the next event has been read, which may be
+        /// before the event itself is available.
+        pub fn pending_delay(&self) -> Option<::std::time::Duration> {
+            if self.timed && self.valid - self.consumed >= TIMING_HEADER {
+                let mut delay = [0u8; TIMING_HEADER];
+                delay.copy_from_slice(&self.buff1[self.consumed .. self.consumed + TIMING_HEADER]);
+                Some(::std::time::Duration::from_nanos(u64::from_le_bytes(delay)))
+            }
+            else {
+                None
+            }
+        }
+        /// The error that stopped the reader, if any.
+        ///
+        /// Once an error is recorded `next` returns `None`, which can be distinguished from
+        /// a lack of data by consulting this method.
+        pub fn error(&self) -> Option<&EventError> {
+            self.error.as_ref()
+        }
+    }
+
+    impl<T, C, R: ::std::io::Read+::std::io::Seek, K> EventReader<T, C, R, K> {
+        /// Repositions the reader at byte `offset` of the underlying stream, discarding buffered data.
+        ///
+        /// The offset must be the start of an event, for example one recorded in an index while the
+        /// capture was written or read. Progress recorded before `offset` is not replayed, and must be
+        /// supplied separately, for example with `seek::SeekEventIterator::with_progress`, for the
+        /// replayed frontier to be correct.
+        pub fn seek(&mut self, offset: u64) -> ::std::io::Result<()> {
+            self.reader.seek(::std::io::SeekFrom::Start(offset))?;
+            self.buff1.clear();
+            self.consumed = 0;
+            self.valid = 0;
+            self.at_end = false;
+            self.error = None;
+            Ok(())
+        }
+        /// The byte offset in the underlying stream of the next event to be read.
+        ///
+        /// Offsets recorded before reading each event can be passed to `seek` to read the event
+        /// again, which allows a first pass over a capture to build an index from times to offsets
+        /// for random-access replay. This requires a seekable reader, such as a `File`.
+        pub fn position(&mut self) -> ::std::io::Result<u64> {
+            let position = self.reader.stream_position()?;
+            Ok(position - (self.valid - self.consumed) as u64)
+        }
+    }
+
+    impl<T, C, R: ::std::io::Read, K: EventCodec<T, C>> EventReader<T, C, R, K> {
+        /// Reads the next event, distinguishing errors from a lack of data.
+        ///
+        /// Returns `Some(Ok(event))` for each event, and `Some(Err(error))` once the stream is found
+        /// to be corrupt or the underlying reader fails. Returns `None` when no complete event is
+        /// available, either because more data may yet arrive or at the end of the stream. An
+        /// incomplete event at the end of the stream is not an error, as the stream may still be
+        /// being written, and the event is returned once the rest of it can be read.
+        ///
+        /// Unlike `next`, which treats errors as a lack of data, this lets consumers log or recover
+        /// from corruption rather than silently stopping.
+        pub fn try_next(&mut self) -> Option<Result<&Event<T, C>, &EventError>> {
+            match self.step() {
+                Some((start, end)) => Some(Ok(self.codec.event(&mut self.buff1[start .. end]))),
+                None => self.error.as_ref().map(Err),
+            }
+        }
+
+        /// Decodes the next event, returning its location in `self.buff1`, or reads more data.
+        fn step(&mut self) -> Option<(usize, usize)> {
+
+            if self.error.is_some() {
+                return None;
+            }
+
+            // skip the timing header, if present, leaving it in place until the event is returned.
+            let prefix = if self.timed { TIMING_HEADER } else { 0 };
+            if self.valid - self.consumed < prefix {
+                // not enough data for the timing header; fall through to read more.
+            }
+            else if self.checksum {
+                // determine whether a complete event is available, and verify it.
+                let header = self.consumed + prefix;
+                let available = self.valid - header;
+                if available >= CHECKSUM_HEADER {
+                    let mut length_bytes = [0u8; 8];
+                    let mut expected = [0u8; 4];
+                    length_bytes.copy_from_slice(&self.buff1[header .. header + 8]);
+                    expected.copy_from_slice(&self.buff1[header + 8 .. header + 12]);
+                    let length = u64::from_le_bytes(length_bytes) as usize;
+                    let expected = u32::from_le_bytes(expected);
+                    if available - CHECKSUM_HEADER >= padded(length) {
+                        let start = header + CHECKSUM_HEADER;
+                        let found = crc32(&[&length_bytes[..], &self.buff1[start .. start + length]]);
+                        if found != expected {
+                            self.error = Some(EventError::Checksum { expected, found });
+                            return None;
+                        }
+                        if self.codec.decode(&mut self.buff1[start .. start + length]).is_none() {
+                            self.error = Some(EventError::Serialization("event failed to decode".to_owned()));
+                            return None;
+                        }
+                        self.consumed = start + padded(length);
+                        return Some((start, start + length));
+                    }
+                }
+            }
+            // if we can decode something, we should just return it! :D
+            else if let Some(length) = self.codec.decode(&mut self.buff1[self.consumed + prefix ..]) {
+                let start = self.consumed + prefix;
+                self.consumed = start + length;
+                return Some((start, start + length));
+            }
+            // if we exhaust data we should shift back (if any shifting to do)
+            if self.consumed > 0 {
+                self.buff2.clear();
+                self.buff2.write_all(&self.buff1[self.consumed..]).unwrap();
                 ::std::mem::swap(&mut self.buff1, &mut self.buff2);
                 self.valid = self.buff1.len();
                 self.consumed = 0;
             }
 
-            if let Ok(len) = self.reader.read(&mut self.bytes[..]) {
-                self.buff1.write_all(&self.bytes[..len]).unwrap();
-                self.valid = self.buff1.len();
+            match self.reader.read(&mut self.bytes[..]) {
+                Ok(len) => {
+                    self.at_end = len == 0;
+                    self.buff1.write_all(&self.bytes[..len]).unwrap();
+                    self.valid = self.buff1.len();
+                },
+                Err(error) => {
+                    // interruptions and non-blocking readers without data are not errors.
+                    match error.kind() {
+                        ::std::io::ErrorKind::Interrupted | ::std::io::ErrorKind::WouldBlock => { },
+                        _ => { self.error = Some(EventError::Io(error)); },
+                    }
+                }
             }
 
             None
         }
     }
+
+    impl<T, C, R: ::std::io::Read, K: EventCodec<T, C>> EventIterator<T, C> for EventReader<T, C, R, K> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            let (start, end) = self.step()?;
+            Some(self.codec.event(&mut self.buff1[start .. end]))
+        }
+    }
+
+    /// Replays events from a timed `EventReader`, reproducing the recorded gaps between them.
+    ///
+    /// Before yielding each event the iterator sleeps until the recorded delay has elapsed since
+    /// it yielded the previous event. This reproduces the arrival pattern of the original capture,
+    /// rather than a fixed rate, and blocks the calling thread while it waits.
+    pub struct TimedReplayIterator<T, C, R: ::std::io::Read, K = AbomonationCodec> {
+        reader: EventReader<T, C, R, K>,
+        last: Option<::std::time::Instant>,
+        waited: bool,
+    }
+
+    impl<T, C, R: ::std::io::Read, K> TimedReplayIterator<T, C, R, K> {
+        /// Allocates a new `TimedReplayIterator` reading events from `reader`.
+        ///
+        /// The reader is switched to expect timed events, as written by `EventWriter::timed`.
+        pub fn new(reader: EventReader<T, C, R, K>) -> Self {
+            TimedReplayIterator {
+                reader: reader.timed(),
+                last: None,
+                waited: false,
+            }
+        }
+        /// Returns the wrapped reader.
+        pub fn into_inner(self) -> EventReader<T, C, R, K> {
+            self.reader
+        }
+    }
+
+    impl<T, C, R: ::std::io::Read, K: EventCodec<T, C>> EventIterator<T, C> for TimedReplayIterator<T, C, R, K> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            if !self.waited {
+                if let Some(delay) = self.reader.pending_delay() {
+                    if let Some(last) = self.last {
+                        let elapsed = last.elapsed();
+                        if elapsed < delay {
+                            ::std::thread::sleep(delay - elapsed);
+                        }
+                    }
+                    self.waited = true;
+                }
+            }
+            let event = self.reader.next();
+            if event.is_some() {
+                self.last = Some(::std::time::Instant::now());
+                self.waited = false;
+            }
+            event
+        }
+    }
+
+    #[test]
+    fn checksum_detects_corruption() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = EventWriter::<u64, Vec<u64>, _>::with_checksum(&mut bytes);
+            writer.push(Event::Messages(0, vec![0, 1, 2]));
+            writer.push(Event::Progress(vec![(0, -1)]));
+        }
+
+        // the intact bytes read back correctly.
+        let mut reader = EventReader::<u64, Vec<u64>, _>::with_checksum(&bytes[..]);
+        let mut count = 0;
+        for _ in 0 .. 4 {
+            while reader.next().is_some() { count += 1; }
+        }
+        assert_eq!(count, 2);
+        assert!(reader.error().is_none());
+
+        // corrupt the payload of the first event.
+        bytes[CHECKSUM_HEADER] ^= 0xFF;
+        let mut reader = EventReader::<u64, Vec<u64>, _>::with_checksum(&bytes[..]);
+        for _ in 0 .. 4 {
+            assert!(reader.next().is_none());
+        }
+        assert!(matches!(reader.error(), Some(EventError::Checksum { .. })));
+        bytes[CHECKSUM_HEADER] ^= 0xFF;
+
+        // corrupt the length of the first event, which the checksum covers.
+        bytes[0] ^= 0x08;
+        let mut reader = EventReader::<u64, Vec<u64>, _>::with_checksum(&bytes[..]);
+        for _ in 0 .. 4 {
+            assert!(reader.next().is_none());
+        }
+        assert!(matches!(reader.error(), Some(EventError::Checksum { .. })));
+    }
+
+    #[test]
+    fn partial_event_completes() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = EventWriter::<u64, Vec<u64>, _>::with_checksum(&mut bytes);
+            writer.push(Event::Messages(0, vec![0, 1, 2]));
+        }
+
+        // a reader tailing a capture that is still being written sees only part of the event.
+        let path = ::std::env::temp_dir().join(format!("timely-partial-{}", ::std::process::id()));
+        let split = bytes.len() / 2;
+        ::std::fs::write(&path, &bytes[.. split]).unwrap();
+        let mut reader = EventReader::<u64, Vec<u64>, _>::with_checksum(::std::fs::File::open(&path).unwrap());
+        for _ in 0 .. 4 {
+            assert!(reader.next().is_none());
+        }
+        assert!(reader.error().is_none());
+
+        // once the rest of the event is written, the reader returns it.
+        ::std::fs::OpenOptions::new().append(true).open(&path).unwrap().write_all(&bytes[split ..]).unwrap();
+        let mut found = None;
+        for _ in 0 .. 4 {
+            if let Some(event) = reader.next() {
+                found = Some(event.clone());
+                break;
+            }
+        }
+        assert_eq!(found, Some(Event::Messages(0, vec![0, 1, 2])));
+        assert!(reader.error().is_none());
+
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn timed_replay() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = EventWriter::<u64, Vec<u64>, _>::new(&mut bytes).timed().checksummed();
+            writer.push(Event::Messages(0, vec![0, 1, 2]));
+            ::std::thread::sleep(::std::time::Duration::from_millis(20));
+            writer.push(Event::Progress(vec![(0, -1)]));
+        }
+
+        let reader = EventReader::<u64, Vec<u64>, _>::with_checksum(&bytes[..]);
+        let mut replay = TimedReplayIterator::new(reader);
+        let start = ::std::time::Instant::now();
+        let mut events = Vec::new();
+        for _ in 0 .. 4 {
+            while let Some(event) = replay.next() { events.push(event.clone()); }
+        }
+        assert_eq!(events, vec![Event::Messages(0, vec![0, 1, 2]), Event::Progress(vec![(0, -1)])]);
+        assert!(start.elapsed() >= ::std::time::Duration::from_millis(20));
+        assert!(replay.into_inner().error().is_none());
+    }
+
+    #[test]
+    fn push_batch_matches_push() {
+
+        let events = vec![Event::Messages(0u64, vec![0u64, 1, 2]), Event::Progress(vec![(0, -1)])];
+
+        let mut pushed = Vec::new();
+        {
+            let mut writer = EventWriter::<u64, Vec<u64>, _>::with_checksum(&mut pushed);
+            for event in events.iter().cloned() {
+                writer.push(event);
+            }
+        }
+        let mut batched = Vec::new();
+        {
+            let mut writer = EventWriter::<u64, Vec<u64>, _>::with_checksum(&mut batched);
+            writer.push_batch(events);
+        }
+        assert_eq!(pushed, batched);
+    }
+
+    #[test]
+    fn try_next_awaits_truncated_event() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = EventWriter::<u64, Vec<u64>, _>::new(&mut bytes);
+            writer.push(Event::Messages(0, vec![0, 1, 2]));
+            writer.push(Event::Progress(vec![(0, -1)]));
+        }
+        bytes.truncate(bytes.len() - 1);
+
+        let mut reader = EventReader::<u64, Vec<u64>, _>::new(&bytes[..]);
+        let mut events = 0;
+        let mut errors = 0;
+        for _ in 0 .. 4 {
+            while let Some(result) = reader.try_next() {
+                match result {
+                    Ok(_) => events += 1,
+                    Err(_) => { errors += 1; break; },
+                }
+            }
+        }
+        // the truncated event may yet be completed, and so is not an error.
+        assert_eq!(events, 1);
+        assert_eq!(errors, 0);
+    }
+
+    #[test]
+    fn durable_syncs_at_progress() {
+
+        let path = ::std::env::temp_dir().join(format!("timely-durable-{}", ::std::process::id()));
+        let file = ::std::fs::File::create(&path).unwrap();
+        let mut writer = DurableEventWriter::<u64, Vec<u64>>::new(file).sync_policy(SyncPolicy::Progress);
+        writer.push(Event::Messages(0, vec![0, 1, 2]));
+        assert_eq!(writer.unsynced(), 1);
+        writer.push(Event::Progress(vec![(0, -1)]));
+        assert_eq!(writer.unsynced(), 0);
+
+        // synchronized events are visible to a reader of the file.
+        let bytes = ::std::fs::read(&path).unwrap();
+        let mut reader = EventReader::<u64, Vec<u64>, _>::new(&bytes[..]);
+        let mut events = Vec::new();
+        for _ in 0 .. 4 {
+            while let Some(event) = reader.next() { events.push(event.clone()); }
+        }
+        assert_eq!(events, vec![Event::Messages(0, vec![0, 1, 2]), Event::Progress(vec![(0, -1)])]);
+
+        drop(writer);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn position_supports_seek() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = EventWriter::<u64, Vec<u64>, _>::new(&mut bytes);
+            for round in 0 .. 3 {
+                writer.push(Event::Messages(round, vec![round]));
+            }
+        }
+
+        // record the offset of each event in a first pass.
+        let mut reader = EventReader::<u64, Vec<u64>, _>::new(::std::io::Cursor::new(&bytes[..]));
+        let mut index = Vec::new();
+        for _ in 0 .. 8 {
+            let position = reader.position().unwrap();
+            if let Some(Event::Messages(time, _)) = reader.next() {
+                index.push((*time, position));
+            }
+        }
+        assert_eq!(index.len(), 3);
+        assert_eq!(index[0].1, 0);
+
+        reader.seek(index[1].1).unwrap();
+        let mut events = Vec::new();
+        for _ in 0 .. 4 {
+            while let Some(event) = reader.next() { events.push(event.clone()); }
+        }
+        assert_eq!(events, vec![Event::Messages(1, vec![1]), Event::Messages(2, vec![2])]);
+    }
+
+    #[test]
+    fn sizing_counts_written_bytes() {
+
+        let sizes = EventSizes::new();
+        let mut bytes = Vec::new();
+        {
+            let mut writer = SizingEventWriter::new(EventWriter::<u64, Vec<u64>, _>::new(&mut bytes), &sizes);
+            writer.push(Event::Messages(0, vec![0, 1, 2]));
+            writer.push(Event::Messages(0, vec![3, 4, 5]));
+            writer.push(Event::Progress(vec![(0, -1)]));
+        }
+
+        assert_eq!(sizes.events(), 3);
+        assert_eq!(sizes.bytes(), bytes.len());
+        assert_eq!(sizes.histogram().len(), 2);
+    }
+
+    #[test]
+    fn rotation_round_trip() {
+
+        let base = ::std::env::temp_dir().join(format!("timely-rotating-{}", ::std::process::id()));
+        {
+            let mut writer = RotatingEventWriter::<u64, Vec<u64>>::new(&base, RotationPolicy::Events(2)).unwrap();
+            for round in 0 .. 5 {
+                writer.push(Event::Messages(round, vec![round]));
+            }
+        }
+        assert!(rotated_path(&base, 2).exists());
+
+        let mut reader = RotatingEventReader::<u64, Vec<u64>>::open(&base).unwrap();
+        let mut events = Vec::new();
+        for _ in 0 .. 16 {
+            while let Some(event) = reader.next() { events.push(event.clone()); }
+        }
+        assert_eq!(events, (0 .. 5).map(|round| Event::Messages(round, vec![round])).collect::<Vec<_>>());
+
+        for index in 0 .. 3 {
+            ::std::fs::remove_file(rotated_path(&base, index)).unwrap();
+        }
+    }
+
+    #[test]
+    fn drop_flushes_buffered_writer() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = EventWriter::<u64, Vec<u64>, _>::new(::std::io::BufWriter::new(&mut bytes));
+            writer.push(Event::Messages(0, vec![0, 1, 2]));
+        }
+        assert!(!bytes.is_empty());
+
+        let mut reader = EventReader::<u64, Vec<u64>, _>::new(&bytes[..]);
+        let mut count = 0;
+        for _ in 0 .. 4 {
+            while reader.next().is_some() { count += 1; }
+        }
+        assert_eq!(count, 1);
+    }
+
+    #[cfg(feature = "postcard")]
+    #[test]
+    fn postcard_round_trip() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = EventWriter::with_codec(&mut bytes, PostcardCodec::default());
+            writer.push(Event::Messages(0u64, vec![0u64, 1, 2]));
+            writer.push(Event::Progress(vec![(0u64, -1)]));
+        }
+
+        let mut reader = EventReader::<u64, Vec<u64>, _, _>::with_codec(&bytes[..], PostcardCodec::default());
+        let mut events = Vec::new();
+        for _ in 0 .. 4 {
+            while let Some(event) = reader.next() { events.push(event.clone()); }
+        }
+        assert_eq!(events, vec![Event::Messages(0, vec![0, 1, 2]), Event::Progress(vec![(0, -1)])]);
+    }
+
+    #[test]
+    fn try_push_reports_io_errors() {
+
+        struct Broken;
+        impl Write for Broken {
+            fn write(&mut self, _buf: &[u8]) -> ::std::io::Result<usize> { Err(::std::io::ErrorKind::BrokenPipe.into()) }
+            fn flush(&mut self) -> ::std::io::Result<()> { Ok(()) }
+        }
+
+        let mut writer = EventWriter::<u64, Vec<u64>, _>::new(Broken);
+        match writer.try_push(Event::Progress(vec![(0, 1)])) {
+            Err(EventError::Io(error)) => assert_eq!(error.kind(), ::std::io::ErrorKind::BrokenPipe),
+            other => panic!("expected an io error, found {:?}", other),
+        }
+    }
+}
+
+/// Length-prefixed frames, shared by the framed, compressed, and columnar formats.
+///
+/// Frames written by `start` and `finish` are their length as a little-endian `u64`, followed
+/// by that many bytes and zeros padding it to a multiple of eight bytes, so that later frames
+/// and abomonated data within them remain aligned to eight bytes, as abomonation requires.
+/// The framed format instead uses a `u32` length without padding, for interoperability.
+mod frames {
+
+    use std::io::Read;
+    use super::EventError;
+
+    /// The number of bytes in a frame header.
+    pub const HEADER: usize = ::std::mem::size_of::<u64>();
+
+    /// The number of bytes a payload of `length` bytes occupies, once padded.
+    fn padded(length: usize) -> usize {
+        length.saturating_add(7) & !7
+    }
+
+    /// Starts a frame at the end of `bytes`, returning its offset for `finish`.
+    pub fn start(bytes: &mut Vec<u8>) -> usize {
+        let offset = bytes.len();
+        bytes.extend_from_slice(&[0u8; HEADER]);
+        offset
+    }
+
+    /// Completes the frame started at `offset`, whose payload is the remainder of `bytes`.
+    pub fn finish(bytes: &mut Vec<u8>, offset: usize) {
+        let length = bytes.len() - offset - HEADER;
+        bytes.resize(offset + HEADER + padded(length), 0);
+        bytes[offset .. offset + HEADER].copy_from_slice(&(length as u64).to_le_bytes());
+    }
+
+    /// The payload length and total extent of the frame at the start of `bytes`, if it is complete.
+    pub fn complete(bytes: &[u8]) -> Option<(usize, usize)> {
+        if bytes.len() < HEADER {
+            return None;
+        }
+        let mut header = [0u8; HEADER];
+        header.copy_from_slice(&bytes[..HEADER]);
+        let length = u64::from_le_bytes(header) as usize;
+        let extent = HEADER.saturating_add(padded(length));
+        if bytes.len() >= extent { Some((length, extent)) } else { None }
+    }
+
+    /// How frames delimit their payloads.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub enum Format {
+        /// A `u64` little-endian length, with the payload padded to eight bytes, as written by `start` and `finish`.
+        Aligned,
+        /// A `u32` little-endian length, with the payload unpadded.
+        Compact,
+    }
+
+    impl Format {
+        /// The number of bytes in a frame header.
+        pub fn header(self) -> usize {
+            match self {
+                Format::Aligned => HEADER,
+                Format::Compact => ::std::mem::size_of::<u32>(),
+            }
+        }
+        /// The payload length and total extent of the frame at the start of `bytes`, if it is complete.
+        pub fn complete(self, bytes: &[u8]) -> Option<(usize, usize)> {
+            match self {
+                Format::Aligned => complete(bytes),
+                Format::Compact => {
+                    if bytes.len() < self.header() {
+                        return None;
+                    }
+                    let mut header = [0u8; 4];
+                    header.copy_from_slice(&bytes[.. 4]);
+                    let length = u32::from_le_bytes(header) as usize;
+                    let extent = self.header().saturating_add(length);
+                    if bytes.len() >= extent { Some((length, extent)) } else { None }
+                },
+            }
+        }
+    }
+
+    /// Reads frames from `R: Read`, buffering partial frames until they are complete.
+    ///
+    /// Each read continues past an earlier end of data, so that input which is still growing,
+    /// such as a file being written, can be followed. A partial frame at the end of the data is
+    /// awaited rather than reported.
+    pub struct FrameReader<R> {
+        reader: R,
+        format: Format,
+        bytes: Vec<u8>,
+        /// Buffered bytes, holding the payload most recently returned by `next`.
+        pub buffer: Vec<u8>,
+        /// The offset in `buffer` of the first frame not yet returned.
+        consumed: usize,
+        eof: bool,
+        /// The error that stopped the reader, if any.
+        pub error: Option<EventError>,
+    }
+
+    impl<R: Read> FrameReader<R> {
+        /// Allocates a new `FrameReader` wrapping a supplied reader, reading frames of `format`.
+        pub fn new(reader: R, format: Format) -> Self {
+            Self {
+                reader,
+                format,
+                bytes: vec![0u8; 1 << 20],
+                buffer: Vec::new(),
+                consumed: 0,
+                eof: false,
+                error: None,
+            }
+        }
+
+        /// True when all frames have been read, or reading has stopped with an error.
+        ///
+        /// The end of the data is that seen by the most recent read, and the data may yet grow.
+        pub fn done(&self) -> bool {
+            self.error.is_some() || (self.eof && self.buffer.len() == self.consumed)
+        }
+
+        /// Returns the location in `self.buffer` of the payload of the next frame, reading if necessary.
+        ///
+        /// The payload remains in place until the next call.
+        pub fn next(&mut self) -> Option<(usize, usize)> {
+            if self.error.is_some() {
+                return None;
+            }
+            if self.format.complete(&self.buffer[self.consumed ..]).is_none() {
+                self.fill();
+            }
+            let (length, extent) = self.format.complete(&self.buffer[self.consumed ..])?;
+            let start = self.consumed + self.format.header();
+            self.consumed += extent;
+            Some((start, start + length))
+        }
+
+        /// Reads from `self.reader` until a frame is complete, or no more data are available.
+        fn fill(&mut self) {
+            // move any partial frame to the front of the buffer, which preserves its alignment.
+            if self.consumed > 0 {
+                let remaining = self.buffer.len() - self.consumed;
+                self.buffer.copy_within(self.consumed .., 0);
+                self.buffer.truncate(remaining);
+                self.consumed = 0;
+            }
+            self.eof = false;
+            while !self.eof && self.error.is_none() && self.format.complete(&self.buffer[..]).is_none() {
+                match self.reader.read(&mut self.bytes[..]) {
+                    Ok(0) => { self.eof = true; },
+                    Ok(len) => { self.buffer.extend_from_slice(&self.bytes[..len]); },
+                    Err(error) => match error.kind() {
+                        ::std::io::ErrorKind::Interrupted => { },
+                        // non-blocking readers without data are not errors: try again later.
+                        ::std::io::ErrorKind::WouldBlock => { break; },
+                        _ => { self.error = Some(EventError::Io(error)); },
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// A length-prefixed binary event pusher and iterator.
+///
+/// Each event is written as a `u32` little-endian length, followed by that many bytes of event
+/// serialized by an `EventCodec`. With the `bincode` feature `new` serializes with bincode, so
+/// that frames can be exchanged with programs outside of timely. The reader buffers partial
+/// frames until they are complete, which makes the format robust to readers that return torn
+/// records, as sockets and pipes may.
+pub mod framed {
+
+    use std::io::{Read, Write};
+    use super::{Event, EventPusher, EventIterator, TryEventPusher, EventError};
+    use super::binary::EventCodec;
+    use super::frames::{FrameReader, Format};
+
+    /// A wrapper for `W: Write` implementing `EventPusher<T, C>` with length-prefixed frames.
+    pub struct FramedEventWriter<T, C, W: Write, K> {
+        stream: W,
+        buffer: Vec<u8>,
+        codec: K,
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    #[cfg(feature = "bincode")]
+    impl<T, C, W: Write> FramedEventWriter<T, C, W, super::binary::BincodeCodec<T, C>> {
+        /// Allocates a new `FramedEventWriter` wrapping a supplied writer, serializing with bincode.
+        pub fn new(w: W) -> Self {
+            Self::with_codec(w, Default::default())
+        }
+    }
+
+    impl<T, C, W: Write, K> FramedEventWriter<T, C, W, K> {
+        /// Allocates a new `FramedEventWriter` serializing events with `codec`.
+        pub fn with_codec(w: W, codec: K) -> Self {
+            Self {
+                stream: w,
+                buffer: Vec::new(),
+                codec,
+                phant: ::std::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<T, C, W: Write, K: EventCodec<T, C>> TryEventPusher<T, C> for FramedEventWriter<T, C, W, K> {
+        fn try_push(&mut self, event: Event<T, C>) -> Result<(), EventError> {
+            let header = Format::Compact.header();
+            self.buffer.clear();
+            self.buffer.resize(header, 0);
+            self.codec.serialize_into(&event, &mut self.buffer)?;
+            let length = self.buffer.len() - header;
+            if length > u32::MAX as usize {
+                return Err(EventError::Serialization("event exceeds the maximum frame length".to_owned()));
+            }
+            self.buffer[.. header].copy_from_slice(&(length as u32).to_le_bytes());
+            self.stream.write_all(&self.buffer[..])?;
+            Ok(())
+        }
+    }
+
+    impl<T, C, W: Write, K: EventCodec<T, C>> EventPusher<T, C> for FramedEventWriter<T, C, W, K> {
+        fn push(&mut self, event: Event<T, C>) {
+            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
+            self.try_push(event).expect("Event serialization/write failed");
+        }
+    }
+
+    /// A wrapper for `R: Read` implementing `EventIterator<T, C>` over length-prefixed frames.
+    pub struct FramedEventReader<T, C, R: Read, K> {
+        frames: FrameReader<R>,
+        payload: Vec<u8>,
+        codec: K,
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    #[cfg(feature = "bincode")]
+    impl<T, C, R: Read> FramedEventReader<T, C, R, super::binary::BincodeCodec<T, C>> {
+        /// Allocates a new `FramedEventReader` wrapping a supplied reader, deserializing with bincode.
+        pub fn new(r: R) -> Self {
+            Self::with_codec(r, Default::default())
+        }
+    }
+
+    impl<T, C, R: Read, K> FramedEventReader<T, C, R, K> {
+        /// Allocates a new `FramedEventReader` deserializing events with `codec`.
+        pub fn with_codec(r: R, codec: K) -> Self {
+            Self {
+                frames: FrameReader::new(r, Format::Compact),
+                payload: Vec::new(),
+                codec,
+                phant: ::std::marker::PhantomData,
+            }
+        }
+
+        /// True when the reader has read all frames of its input, or has stopped with an error.
+        ///
+        /// A `None` result from `next` only indicates that no complete frame is available yet;
+        /// this method distinguishes a stream that has ended, as of the most recent read, from
+        /// one with a frame still in progress. Reading resumes if the input grows.
+        pub fn done(&self) -> bool {
+            self.frames.done()
+        }
+
+        /// The error that stopped the reader, if any.
+        ///
+        /// Once an error is recorded `next` returns `None`, which can be distinguished from
+        /// a lack of data by consulting this method.
+        pub fn error(&self) -> Option<&EventError> {
+            self.frames.error.as_ref()
+        }
+    }
+
+    impl<T, C, R: Read, K: EventCodec<T, C>> EventIterator<T, C> for FramedEventReader<T, C, R, K> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            let (start, end) = self.frames.next()?;
+            // The four byte length leaves payloads unaligned, which some codecs cannot decode in place.
+            self.payload.clear();
+            self.payload.extend_from_slice(&self.frames.buffer[start .. end]);
+            match self.codec.decode(&mut self.payload[..]) {
+                Some(length) if length == self.payload.len() => Some(self.codec.event(&mut self.payload[..])),
+                _ => {
+                    self.frames.error = Some(EventError::Serialization("frame failed to decode".to_owned()));
+                    None
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_torn_reads() {
+
+        use super::binary::AbomonationCodec;
+
+        // A reader that returns at most one byte per read.
+        struct Trickle(::std::io::Cursor<Vec<u8>>);
+        impl Read for Trickle {
+            fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+                let len = ::std::cmp::min(buf.len(), 1);
+                self.0.read(&mut buf[..len])
+            }
+        }
+
+        let mut writer = FramedEventWriter::<u64, Vec<u64>, _, _>::with_codec(Vec::new(), AbomonationCodec);
+        writer.push(Event::Messages(3, vec![1, 2, 3]));
+        writer.push(Event::Progress(vec![(3, -1), (4, 1)]));
+
+        let mut reader = FramedEventReader::<u64, Vec<u64>, _, _>::with_codec(Trickle(::std::io::Cursor::new(writer.stream)), AbomonationCodec);
+        assert_eq!(reader.next(), Some(&Event::Messages(3, vec![1, 2, 3])));
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(3, -1), (4, 1)])));
+        assert_eq!(reader.next(), None);
+        assert!(reader.done());
+    }
+
+    #[test]
+    fn truncated_stream_resumes() {
+
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        use super::binary::AbomonationCodec;
+
+        // A reader over bytes that may grow after it has read to their end.
+        struct Growing(Rc<RefCell<Vec<u8>>>, usize);
+        impl Read for Growing {
+            fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+                let bytes = self.0.borrow();
+                let len = ::std::cmp::min(buf.len(), bytes.len() - self.1);
+                buf[.. len].copy_from_slice(&bytes[self.1 .. self.1 + len]);
+                self.1 += len;
+                Ok(len)
+            }
+        }
+
+        let mut writer = FramedEventWriter::<u64, Vec<u64>, _, _>::with_codec(Vec::new(), AbomonationCodec);
+        writer.push(Event::Messages(3, vec![1, 2, 3]));
+        writer.push(Event::Progress(vec![(3, -1), (4, 1)]));
+        let split = writer.stream.len() - 1;
+
+        let bytes = Rc::new(RefCell::new(writer.stream[.. split].to_vec()));
+        let mut reader = FramedEventReader::<u64, Vec<u64>, _, _>::with_codec(Growing(bytes.clone(), 0), AbomonationCodec);
+        assert_eq!(reader.next(), Some(&Event::Messages(3, vec![1, 2, 3])));
+        assert_eq!(reader.next(), None);
+        assert!(!reader.done());
+        assert!(reader.error().is_none());
+
+        bytes.borrow_mut().extend_from_slice(&writer.stream[split ..]);
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(3, -1), (4, 1)])));
+        assert_eq!(reader.next(), None);
+        assert!(reader.done());
+        assert!(reader.error().is_none());
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode_wire_format() {
+
+        let event = Event::Messages(3u64, vec![1u64, 2, 3]);
+        let mut writer = FramedEventWriter::<u64, Vec<u64>, _, _>::new(Vec::new());
+        writer.push(event.clone());
+
+        let payload = ::bincode_dep::serialize(&event).unwrap();
+        let mut expected = (payload.len() as u32).to_le_bytes().to_vec();
+        expected.extend_from_slice(&payload[..]);
+        assert_eq!(writer.stream, expected);
+
+        let mut reader = FramedEventReader::<u64, Vec<u64>, _, _>::new(&expected[..]);
+        assert_eq!(reader.next(), Some(&event));
+        assert_eq!(reader.next(), None);
+        assert!(reader.done());
+    }
+}
+
+/// A compressed binary event pusher and iterator.
+///
+/// Events are serialized into an uncompressed buffer, which is compressed and written as a
+/// single length-prefixed frame whenever the writer is flushed. The reader decompresses each
+/// frame in turn, and so handles the concatenated frames produced by repeated flushes.
+pub mod compressed {
+
+    use std::io::{Read, Write};
+    use abomonation::Abomonation;
+    use super::{Event, EventPusher, EventIterator, TryEventPusher, EventError};
+    use super::frames::{self, FrameReader, Format};
+
+    /// The number of uncompressed bytes a writer buffers before flushing on its own.
+    const FLUSH_THRESHOLD: usize = 1 << 20;
+
+    /// A method of compressing and decompressing bytes.
+    pub trait Codec {
+        /// Appends the compressed form of `input` to `output`.
+        fn compress(&mut self, input: &[u8], output: &mut Vec<u8>) -> ::std::io::Result<()>;
+        /// Appends the decompressed form of `input` to `output`.
+        fn decompress(&mut self, input: &[u8], output: &mut Vec<u8>) -> ::std::io::Result<()>;
+    }
+
+    /// A codec that leaves bytes as they are.
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Uncompressed;
+
+    impl Codec for Uncompressed {
+        fn compress(&mut self, input: &[u8], output: &mut Vec<u8>) -> ::std::io::Result<()> {
+            output.extend_from_slice(input);
+            Ok(())
+        }
+        fn decompress(&mut self, input: &[u8], output: &mut Vec<u8>) -> ::std::io::Result<()> {
+            output.extend_from_slice(input);
+            Ok(())
+        }
+    }
+
+    /// A codec using zstd compression at a configurable level.
+    #[cfg(feature = "zstd")]
+    #[derive(Debug, Default, Clone, Copy)]
+    pub struct Zstd {
+        level: i32,
+    }
+
+    #[cfg(feature = "zstd")]
+    impl Zstd {
+        /// Creates a zstd codec compressing at `level`, where zero indicates zstd's default.
+        pub fn new(level: i32) -> Self {
+            Zstd { level }
+        }
+    }
+
+    #[cfg(feature = "zstd")]
+    impl Codec for Zstd {
+        fn compress(&mut self, input: &[u8], output: &mut Vec<u8>) -> ::std::io::Result<()> {
+            ::zstd::stream::copy_encode(input, output, self.level)
+        }
+        fn decompress(&mut self, input: &[u8], output: &mut Vec<u8>) -> ::std::io::Result<()> {
+            ::zstd::stream::copy_decode(input, output)
+        }
+    }
+
+    /// A wrapper for `W: Write` implementing `EventPusher<T, C>`, compressing with `Z: Codec`.
+    ///
+    /// Events are only written once the writer is flushed, which happens when enough bytes
+    /// have accumulated, when `flush` is called, and when the writer is dropped. Callers may
+    /// want to call `flush` at frontier boundaries, so that readers see complete times.
+    pub struct CompressedEventWriter<T, C, W: Write, Z: Codec> {
+        stream: W,
+        codec: Z,
+        pending: Vec<u8>,
+        buffer: Vec<u8>,
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    impl<T, C, W: Write, Z: Codec> CompressedEventWriter<T, C, W, Z> {
+        /// Allocates a new `CompressedEventWriter` wrapping a supplied writer and codec.
+        pub fn new(w: W, codec: Z) -> Self {
+            Self {
+                stream: w,
+                codec,
+                pending: Vec::new(),
+                buffer: Vec::new(),
+                phant: ::std::marker::PhantomData,
+            }
+        }
+
+        /// Compresses and writes all buffered events as one frame, and flushes the writer.
+        pub fn flush(&mut self) -> ::std::io::Result<()> {
+            if !self.pending.is_empty() {
+                self.buffer.clear();
+                let start = frames::start(&mut self.buffer);
+                self.codec.compress(&self.pending[..], &mut self.buffer)?;
+                frames::finish(&mut self.buffer, start);
+                self.stream.write_all(&self.buffer[..])?;
+                self.pending.clear();
+            }
+            self.stream.flush()
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, W: Write, Z: Codec> TryEventPusher<T, C> for CompressedEventWriter<T, C, W, Z> {
+        fn try_push(&mut self, event: Event<T, C>) -> Result<(), EventError> {
+            // each record is itself a frame, which keeps records aligned within the decompressed bytes.
+            let start = frames::start(&mut self.pending);
+            if let Err(error) = unsafe { ::abomonation::encode(&event, &mut self.pending) } {
+                self.pending.truncate(start);
+                return Err(EventError::Serialization(error.to_string()));
+            }
+            frames::finish(&mut self.pending, start);
+            if self.pending.len() >= FLUSH_THRESHOLD {
+                self.flush()?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, W: Write, Z: Codec> EventPusher<T, C> for CompressedEventWriter<T, C, W, Z> {
+        fn push(&mut self, event: Event<T, C>) {
+            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
+            self.try_push(event).expect("Event serialization/write failed");
+        }
+    }
+
+    impl<T, C, W: Write, Z: Codec> Drop for CompressedEventWriter<T, C, W, Z> {
+        fn drop(&mut self) {
+            // Errors cannot be reported here; call `flush` first to observe them.
+            let _ = self.flush();
+        }
+    }
+
+    /// A wrapper for `R: Read` implementing `EventIterator<T, C>`, decompressing with `Z: Codec`.
+    ///
+    /// A frame that fails to decompress or decode stops the reader, rather than being skipped,
+    /// as skipping its progress events would leave the replayed frontier inconsistent with the
+    /// data. The error is then available from `error`.
+    pub struct CompressedEventReader<T, C, R: Read, Z: Codec> {
+        frames: FrameReader<R>,
+        codec: Z,
+        decoded: Vec<u8>,
+        offset: usize,
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    impl<T, C, R: Read, Z: Codec> CompressedEventReader<T, C, R, Z> {
+        /// Allocates a new `CompressedEventReader` wrapping a supplied reader and codec.
+        pub fn new(r: R, codec: Z) -> Self {
+            Self {
+                frames: FrameReader::new(r, Format::Aligned),
+                codec,
+                decoded: Vec::new(),
+                offset: 0,
+                phant: ::std::marker::PhantomData,
+            }
+        }
+
+        /// The error that stopped the reader, if any.
+        ///
+        /// Once an error is recorded `next` returns `None`, which can be distinguished from
+        /// a lack of data by consulting this method.
+        pub fn error(&self) -> Option<&EventError> {
+            self.frames.error.as_ref()
+        }
+
+        /// Decompresses the next complete frame into `self.decoded`, reading if necessary.
+        fn next_frame(&mut self) {
+            if let Some((start, end)) = self.frames.next() {
+                self.decoded.clear();
+                self.offset = 0;
+                if let Err(error) = self.codec.decompress(&self.frames.buffer[start .. end], &mut self.decoded) {
+                    self.frames.error = Some(EventError::Io(error));
+                    self.decoded.clear();
+                }
+            }
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, R: Read, Z: Codec> EventIterator<T, C> for CompressedEventReader<T, C, R, Z> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+
+            if self.offset == self.decoded.len() {
+                self.next_frame();
+            }
+            if self.frames.error.is_some() || self.offset == self.decoded.len() {
+                return None;
+            }
+
+            let (length, extent) = match frames::complete(&self.decoded[self.offset ..]) {
+                Some(record) => record,
+                None => {
+                    self.frames.error = Some(EventError::Serialization("truncated record in frame".to_owned()));
+                    return None;
+                },
+            };
+            let start = self.offset + frames::HEADER;
+            self.offset += extent;
+            match unsafe { ::abomonation::decode::<Event<T, C>>(&mut self.decoded[start .. start + length]) } {
+                Some((event, rest)) if rest.is_empty() => Some(event),
+                _ => {
+                    self.frames.error = Some(EventError::Serialization("record failed to decode".to_owned()));
+                    None
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_multiple_frames() {
+
+        let mut writer = CompressedEventWriter::<u64, Vec<u64>, _, _>::new(Vec::new(), Uncompressed);
+        writer.push(Event::Messages(3, vec![1, 2, 3]));
+        writer.flush().unwrap();
+        writer.push(Event::Progress(vec![(3, -1), (4, 1)]));
+        writer.push(Event::Messages(4, vec![4]));
+        writer.flush().unwrap();
+        let bytes = ::std::mem::take(&mut writer.stream);
+
+        let mut reader = CompressedEventReader::<u64, Vec<u64>, _, _>::new(&bytes[..], Uncompressed);
+        assert_eq!(reader.next(), Some(&Event::Messages(3, vec![1, 2, 3])));
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(3, -1), (4, 1)])));
+        assert_eq!(reader.next(), Some(&Event::Messages(4, vec![4])));
+        assert_eq!(reader.next(), None);
+        assert!(reader.error().is_none());
+    }
+
+    #[test]
+    fn corrupt_frame_reports_error() {
+
+        let mut writer = CompressedEventWriter::<u64, Vec<u64>, _, _>::new(Vec::new(), Uncompressed);
+        writer.push(Event::Messages(3, vec![1, 2, 3]));
+        writer.push(Event::Progress(vec![(3, -1), (4, 1)]));
+        writer.flush().unwrap();
+        let mut bytes = ::std::mem::take(&mut writer.stream);
+        // claim a second record longer than the frame holds.
+        let second = frames::HEADER + frames::complete(&bytes[frames::HEADER ..]).unwrap().1;
+        bytes[second] = 0xFF;
+
+        let mut reader = CompressedEventReader::<u64, Vec<u64>, _, _>::new(&bytes[..], Uncompressed);
+        assert_eq!(reader.next(), Some(&Event::Messages(3, vec![1, 2, 3])));
+        assert_eq!(reader.next(), None);
+        assert!(matches!(reader.error(), Some(EventError::Serialization(_))));
+    }
+}
+
+/// An event iterator merging several captured streams.
+pub mod merge {
+
+    use crate::progress::{ChangeBatch, Timestamp};
+    use super::{Event, EventIterator};
+
+    /// Merges several event iterators into one, interleaving messages in timestamp order.
+    ///
+    /// The iterator buffers one pending event per input. Among buffered messages, the one with
+    /// the least timestamp is produced first; once no messages are buffered, all buffered progress
+    /// updates are combined and produced as a single progress event. As messages are only moved
+    /// earlier and progress updates only later, the merged stream respects the replay protocol.
+    ///
+    /// The inputs are each assumed to start with a capability at `T::minimum()`, and the merged
+    /// stream first reports the adjustment to a single such capability.
+    pub struct MergeEventIterator<T, C> {
+        inputs: Vec<Box<dyn EventIterator<T, C>>>,
+        pending: Vec<Option<Event<T, C>>>,
+        counts: Vec<ChangeBatch<T>>,
+        started: bool,
+        current: Option<Event<T, C>>,
+    }
+
+    impl<T: Timestamp, C: Clone> MergeEventIterator<T, C> {
+        /// Allocates a new `MergeEventIterator` from a list of inputs.
+        pub fn new(inputs: Vec<Box<dyn EventIterator<T, C>>>) -> Self {
+            let pending = inputs.iter().map(|_| None).collect();
+            let counts = inputs.iter().map(|_| ChangeBatch::new_from(T::minimum(), 1)).collect();
+            Self {
+                inputs,
+                pending,
+                counts,
+                started: false,
+                current: None,
+            }
+        }
+
+        /// True when every input has released all of its capabilities.
+        ///
+        /// A `None` result from `next` only indicates that no input currently has an event;
+        /// this method indicates that none of the inputs will produce further events.
+        pub fn done(&mut self) -> bool {
+            self.counts.iter_mut().all(|counts| counts.is_empty())
+        }
+    }
+
+    impl<T: Timestamp, C: Clone> EventIterator<T, C> for MergeEventIterator<T, C> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+
+            if !self.started {
+                self.started = true;
+                // Each input starts with a capability, but the merged stream only with one.
+                let adjustment = (self.inputs.len() as i64) - 1;
+                if adjustment != 0 {
+                    self.current = Some(Event::Progress(vec![(T::minimum(), adjustment)]));
+                    return self.current.as_ref();
+                }
+            }
+
+            loop {
+                for (input, pending) in self.inputs.iter_mut().zip(self.pending.iter_mut()) {
+                    if pending.is_none() {
+                        *pending = input.next().cloned();
+                    }
+                }
+
+                // Produce the buffered message with the least timestamp, if any.
+                let least =
+                self.pending
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, event)| match event {
+                        Some(Event::Messages(time, _)) => Some((time, index)),
+                        _ => None,
+                    })
+                    .min()
+                    .map(|(_time, index)| index);
+
+                if let Some(index) = least {
+                    self.current = self.pending[index].take();
+                    return self.current.as_ref();
+                }
+
+                // Otherwise, combine all buffered progress updates.
+                let mut found = false;
+                let mut progress = ChangeBatch::new();
+                for (pending, counts) in self.pending.iter_mut().zip(self.counts.iter_mut()) {
+                    if let Some(Event::Progress(updates)) = pending.take() {
+                        found = true;
+                        counts.extend(updates.iter().cloned());
+                        progress.extend(updates.into_iter());
+                    }
+                }
+
+                if !found {
+                    return None;
+                }
+                if !progress.is_empty() {
+                    self.current = Some(Event::Progress(progress.into_inner()));
+                    return self.current.as_ref();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn merge_orders_messages() {
+
+        use std::rc::Rc;
+        use super::EventPusher;
+        use super::link::EventLink;
+
+        let mut link1 = Rc::new(EventLink::new());
+        let mut link2 = Rc::new(EventLink::new());
+        let mut merged = MergeEventIterator::<u64, Vec<u64>>::new(vec![Box::new(link1.clone()), Box::new(link2.clone())]);
+
+        link1.push(Event::Messages(5, vec![5]));
+        link1.push(Event::Progress(vec![(0, -1)]));
+        link2.push(Event::Messages(1, vec![1]));
+        link2.push(Event::Progress(vec![(0, -1)]));
+
+        assert_eq!(merged.next(), Some(&Event::Progress(vec![(0, 1)])));
+        assert_eq!(merged.next(), Some(&Event::Messages(1, vec![1])));
+        assert_eq!(merged.next(), Some(&Event::Messages(5, vec![5])));
+        assert_eq!(merged.next(), Some(&Event::Progress(vec![(0, -2)])));
+        assert_eq!(merged.next(), None);
+        assert!(merged.done());
+    }
+}
+
+/// A binary event pusher and iterator that writes events in batches of columns.
+///
+/// Rather than serializing each event individually, the writer accumulates events into
+/// columns of message times, message containers, and progress updates, and serializes all
+/// columns at once. This amortizes per-event overhead for captures with many small messages.
+pub mod columnar {
+
+    use std::collections::VecDeque;
+    use std::io::{Read, Write};
+    use abomonation::Abomonation;
+    use super::{Event, EventPusher, EventIterator, TryEventPusher, EventError};
+    use super::frames::{self, FrameReader, Format};
+
+    /// The default number of events in a batch.
+    const DEFAULT_BATCH: usize = 1024;
+
+    /// A batch of events, organized by columns.
+    #[derive(Abomonation)]
+    struct Columns<T, C> {
+        /// For each event in order, true for messages and false for progress.
+        kinds: Vec<bool>,
+        /// The times of message events.
+        times: Vec<T>,
+        /// The containers of message events.
+        containers: Vec<C>,
+        /// The updates of progress events.
+        progress: Vec<Vec<(T, i64)>>,
+    }
+
+    impl<T, C> Columns<T, C> {
+        fn new() -> Self {
+            Columns { kinds: Vec::new(), times: Vec::new(), containers: Vec::new(), progress: Vec::new() }
+        }
+        fn clear(&mut self) {
+            self.kinds.clear();
+            self.times.clear();
+            self.containers.clear();
+            self.progress.clear();
+        }
+    }
+
+    /// A wrapper for `W: Write` implementing `EventPusher<T, C>` with batches of columns.
+    ///
+    /// Events are only written once a batch fills, when `flush` is called, or when dropped.
+    pub struct ColumnarEventWriter<T: Abomonation, C: Abomonation, W: Write> {
+        stream: W,
+        columns: Columns<T, C>,
+        batch: usize,
+        buffer: Vec<u8>,
+    }
+
+    impl<T: Abomonation, C: Abomonation, W: Write> ColumnarEventWriter<T, C, W> {
+        /// Allocates a new `ColumnarEventWriter` wrapping a supplied writer.
+        pub fn new(w: W) -> Self {
+            Self::with_batch(w, DEFAULT_BATCH)
+        }
+        /// Allocates a new `ColumnarEventWriter` writing batches of `batch` events.
+        pub fn with_batch(w: W, batch: usize) -> Self {
+            Self {
+                stream: w,
+                columns: Columns::new(),
+                batch,
+                buffer: Vec::new(),
+            }
+        }
+        /// Writes all accumulated events as one batch, and flushes the writer.
+        pub fn flush(&mut self) -> ::std::io::Result<()> {
+            if !self.columns.kinds.is_empty() {
+                self.buffer.clear();
+                let start = frames::start(&mut self.buffer);
+                unsafe { ::abomonation::encode(&self.columns, &mut self.buffer)?; }
+                frames::finish(&mut self.buffer, start);
+                self.stream.write_all(&self.buffer[..])?;
+                self.columns.clear();
+            }
+            self.stream.flush()
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, W: Write> TryEventPusher<T, C> for ColumnarEventWriter<T, C, W> {
+        fn try_push(&mut self, event: Event<T, C>) -> Result<(), EventError> {
+            match event {
+                Event::Progress(updates) => {
+                    self.columns.kinds.push(false);
+                    self.columns.progress.push(updates);
+                },
+                Event::Messages(time, data) => {
+                    self.columns.kinds.push(true);
+                    self.columns.times.push(time);
+                    self.columns.containers.push(data);
+                },
+            }
+            if self.columns.kinds.len() >= self.batch {
+                self.flush()?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, W: Write> EventPusher<T, C> for ColumnarEventWriter<T, C, W> {
+        fn push(&mut self, event: Event<T, C>) {
+            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
+            self.try_push(event).expect("Event serialization/write failed");
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, W: Write> Drop for ColumnarEventWriter<T, C, W> {
+        fn drop(&mut self) {
+            // Errors cannot be reported here; call `flush` first to observe them.
+            let _ = self.flush();
+        }
+    }
+
+    /// A wrapper for `R: Read` implementing `EventIterator<T, C>` over batches of columns.
+    ///
+    /// A batch that fails to decode stops the reader, rather than being skipped, as skipping its
+    /// progress events would leave the replayed frontier inconsistent with the data. The error is
+    /// then available from `error`.
+    pub struct ColumnarEventReader<T, C, R: Read> {
+        frames: FrameReader<R>,
+        events: VecDeque<Event<T, C>>,
+        current: Option<Event<T, C>>,
+    }
+
+    impl<T, C, R: Read> ColumnarEventReader<T, C, R> {
+        /// Allocates a new `ColumnarEventReader` wrapping a supplied reader.
+        pub fn new(r: R) -> Self {
+            Self {
+                frames: FrameReader::new(r, Format::Aligned),
+                events: VecDeque::new(),
+                current: None,
+            }
+        }
+
+        /// The error that stopped the reader, if any.
+        ///
+        /// Once an error is recorded `next` returns `None`, which can be distinguished from
+        /// a lack of data by consulting this method.
+        pub fn error(&self) -> Option<&EventError> {
+            self.frames.error.as_ref()
+        }
+    }
+
+    impl<T: Abomonation+Clone, C: Abomonation+Clone, R: Read> ColumnarEventReader<T, C, R> {
+        /// Unpacks the next complete batch into `self.events`, reading if necessary.
+        fn next_batch(&mut self) {
+            if let Some((start, end)) = self.frames.next() {
+                let columns = match unsafe { ::abomonation::decode::<Columns<T, C>>(&mut self.frames.buffer[start .. end]) } {
+                    Some((columns, rest)) if rest.is_empty() => columns,
+                    _ => {
+                        self.frames.error = Some(EventError::Serialization("batch failed to decode".to_owned()));
+                        return;
+                    },
+                };
+                let messages = columns.kinds.iter().filter(|is_message| **is_message).count();
+                if messages != columns.times.len() || messages != columns.containers.len() || columns.kinds.len() - messages != columns.progress.len() {
+                    self.frames.error = Some(EventError::Serialization("batch columns have inconsistent lengths".to_owned()));
+                    return;
+                }
+                let mut times = columns.times.iter();
+                let mut containers = columns.containers.iter();
+                let mut progress = columns.progress.iter();
+                for &is_message in columns.kinds.iter() {
+                    if is_message {
+                        if let (Some(time), Some(data)) = (times.next(), containers.next()) {
+                            self.events.push_back(Event::Messages(time.clone(), data.clone()));
+                        }
+                    }
+                    else if let Some(updates) = progress.next() {
+                        self.events.push_back(Event::Progress(updates.clone()));
+                    }
+                }
+            }
+        }
+    }
+
+    impl<T: Abomonation+Clone, C: Abomonation+Clone, R: Read> EventIterator<T, C> for ColumnarEventReader<T, C, R> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            if self.events.is_empty() {
+                self.next_batch();
+            }
+            self.current = self.events.pop_front();
+            self.current.as_ref()
+        }
+    }
+
+    #[test]
+    fn round_trip_batches() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = ColumnarEventWriter::<u64, Vec<u64>, _>::with_batch(&mut bytes, 2);
+            writer.push(Event::Messages(3, vec![1, 2, 3]));
+            writer.push(Event::Progress(vec![(3, -1), (4, 1)]));
+            writer.push(Event::Messages(4, vec![4]));
+        }
+
+        let mut reader = ColumnarEventReader::<u64, Vec<u64>, _>::new(&bytes[..]);
+        assert_eq!(reader.next(), Some(&Event::Messages(3, vec![1, 2, 3])));
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(3, -1), (4, 1)])));
+        assert_eq!(reader.next(), Some(&Event::Messages(4, vec![4])));
+        assert_eq!(reader.next(), None);
+        assert!(reader.error().is_none());
+    }
+
+    #[test]
+    fn corrupt_batch_reports_error() {
+
+        let mut bytes = Vec::new();
+        {
+            let mut writer = ColumnarEventWriter::<u64, Vec<u64>, _>::with_batch(&mut bytes, 2);
+            writer.push(Event::Messages(3, vec![1, 2, 3]));
+            writer.push(Event::Progress(vec![(3, -1), (4, 1)]));
+        }
+        // the batch claims fewer bytes than its columns occupy.
+        bytes[0] -= 8;
+
+        let mut reader = ColumnarEventReader::<u64, Vec<u64>, _>::new(&bytes[..]);
+        assert_eq!(reader.next(), None);
+        assert!(reader.error().is_some());
+    }
+}
+
+/// An event pusher that writes to a TCP socket, reconnecting when the connection fails.
+pub mod tcp {
+
+    use std::collections::VecDeque;
+    use std::io::Write;
+    use std::net::{SocketAddr, TcpStream};
+    use std::time::{Duration, Instant};
+    use abomonation::Abomonation;
+    use crate::progress::ChangeBatch;
+    use super::{Event, EventPusher};
+
+    /// The initial delay before reconnecting after a failure.
+    const INITIAL_BACKOFF: Duration = Duration::from_millis(10);
+    /// The maximum delay between attempts to reconnect.
+    const MAXIMUM_BACKOFF: Duration = Duration::from_secs(10);
+    /// The default limit on the time spent establishing a connection, during which the worker blocks.
+    const CONNECT_TIMEOUT: Duration = Duration::from_millis(100);
+
+    /// An event pusher writing to a TCP connection to `address`, in the format of `EventWriter`.
+    ///
+    /// When a write fails, the connection is discarded and re-established on a later push, with
+    /// exponential backoff between attempts. While disconnected, up to `capacity` events are
+    /// buffered; beyond that, the oldest messages are dropped and counted. Progress events are
+    /// never dropped, but are instead coalesced, as dropping them would prevent replay from
+    /// completing. The receiving end sees each connection as a separate stream, and should use
+    /// a new `EventReader` for each.
+    ///
+    /// Connecting blocks the pushing worker for at most the connection timeout, which defaults to
+    /// 100 milliseconds and can be changed with `with_connect_timeout`.
+    pub struct ReconnectingTcpEventPusher<T, C> {
+        address: SocketAddr,
+        connect_timeout: Duration,
+        stream: Option<TcpStream>,
+        pending: VecDeque<Event<T, C>>,
+        capacity: usize,
+        dropped: usize,
+        backoff: Duration,
+        next_attempt: Instant,
+        buffer: Vec<u8>,
+    }
+
+    impl<T, C> ReconnectingTcpEventPusher<T, C> {
+        /// Allocates a new pusher for `address`, buffering at most `capacity` events while disconnected.
+        ///
+        /// The connection is first attempted on the first push.
+        pub fn new(address: SocketAddr, capacity: usize) -> Self {
+            Self {
+                address,
+                connect_timeout: CONNECT_TIMEOUT,
+                stream: None,
+                pending: VecDeque::new(),
+                capacity,
+                dropped: 0,
+                backoff: INITIAL_BACKOFF,
+                next_attempt: Instant::now(),
+                buffer: Vec::new(),
+            }
+        }
+        /// Sets the limit on the time spent establishing each connection.
+        pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+            self.connect_timeout = timeout;
+            self
+        }
+        /// The number of message events dropped due to buffer overflow.
+        pub fn dropped(&self) -> usize { self.dropped }
+        /// The number of events buffered and not yet written.
+        pub fn pending(&self) -> usize { self.pending.len() }
+        /// True if the pusher currently holds a connection.
+        pub fn connected(&self) -> bool { self.stream.is_some() }
+
+        /// Discards the connection, and schedules the next attempt to reconnect.
+        fn disconnect(&mut self) {
+            self.stream = None;
+            self.next_attempt = Instant::now() + self.backoff;
+            self.backoff = ::std::cmp::min(self.backoff * 2, MAXIMUM_BACKOFF);
+        }
+    }
+
+    impl<T: Ord+Clone, C> ReconnectingTcpEventPusher<T, C> {
+        /// Removes the oldest events until at most `self.capacity` remain, besides coalesced progress.
+        ///
+        /// Removed messages are dropped, and removed progress events are coalesced into one event
+        /// at the front of the queue, which precedes all remaining events as they did.
+        fn shed(&mut self) {
+            let mut progress = ChangeBatch::new();
+            while self.pending.len() > self.capacity {
+                match self.pending.pop_front() {
+                    Some(Event::Messages(..)) => { self.dropped += 1; },
+                    Some(Event::Progress(updates)) => { progress.extend(updates.into_iter()); },
+                    None => { break; },
+                }
+            }
+            if !progress.is_empty() {
+                self.pending.push_front(Event::Progress(progress.into_inner()));
+            }
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation> ReconnectingTcpEventPusher<T, C> {
+        /// Writes as many pending events as possible, connecting if needed and permitted.
+        fn drain(&mut self) {
+            if self.stream.is_none() && Instant::now() >= self.next_attempt {
+                match TcpStream::connect_timeout(&self.address, self.connect_timeout) {
+                    Ok(stream) => {
+                        self.stream = Some(stream);
+                        self.backoff = INITIAL_BACKOFF;
+                    },
+                    Err(_) => self.disconnect(),
+                }
+            }
+            while let Some(stream) = self.stream.as_mut() {
+                if let Some(event) = self.pending.front() {
+                    self.buffer.clear();
+                    unsafe { ::abomonation::encode(event, &mut self.buffer).expect("Event serialization failed"); }
+                    if stream.write_all(&self.buffer[..]).is_ok() {
+                        self.pending.pop_front();
+                    }
+                    else {
+                        self.disconnect();
+                    }
+                }
+                else {
+                    break;
+                }
+            }
+        }
+    }
+
+    impl<T: Abomonation+Ord+Clone, C: Abomonation> EventPusher<T, C> for ReconnectingTcpEventPusher<T, C> {
+        fn push(&mut self, event: Event<T, C>) {
+            self.pending.push_back(event);
+            // write what we can before shedding, so that nothing is dropped while connected.
+            self.drain();
+            self.shed();
+        }
+    }
+
+    #[test]
+    fn loopback_capture_and_replay() {
+
+        use std::net::TcpListener;
+        use crate::dataflow::operators::{Capture, ToStream};
+        use crate::dataflow::operators::capture::{EventReader, Extract, Replay};
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        // the pusher connects on the first event, and disconnects when the dataflow is dropped.
+        crate::example(move |scope| {
+            (0 .. 10u64).to_stream(scope).capture_into(ReconnectingTcpEventPusher::new(address, 16));
+        });
+
+        let (stream, _) = listener.accept().unwrap();
+        let captured = crate::example(move |scope| {
+            Some(EventReader::<u64, Vec<u64>, _>::new(stream))
+                .replay_into(scope)
+                .capture()
+        });
+        assert_eq!(captured.extract().into_iter().flat_map(|x| x.1).collect::<Vec<_>>(), (0 .. 10).collect::<Vec<_>>());
+    }
+}
+
+/// An asynchronous binary event pusher, for use with tokio.
+///
+/// Timely workers push events synchronously, and should not block on an asynchronous sink.
+/// Instead, the worker captures into a `tokio::sync::mpsc::UnboundedSender`, which implements
+/// `EventPusher`, and a task on the async runtime drains the receiver into an `AsyncEventWriter`.
+///
+/// ```ignore
+/// let (send, recv) = tokio::sync::mpsc::unbounded_channel();
+///
+/// // on the async runtime, write received events to the socket.
+/// runtime.spawn(async move {
+///     let socket = tokio::net::TcpStream::connect("127.0.0.1:8000").await.unwrap();
+///     forward(recv, &mut AsyncEventWriter::new(socket)).await
+/// });
+///
+/// // on the timely worker, capture into the sender.
+/// worker.dataflow::<u64,_,_>(|scope| {
+///     (0..10u64).to_stream(scope).capture_into(send);
+/// });
+/// ```
+#[cfg(feature = "tokio")]
+pub mod asynchronous {
+
+    use std::future::Future;
+    use std::pin::Pin;
+    use abomonation::Abomonation;
+    use tokio::io::{AsyncWrite, AsyncWriteExt};
+    use tokio::sync::mpsc::{UnboundedSender, UnboundedReceiver};
+    use super::{Event, EventPusher};
+
+    /// Asynchronously receives `Event<T, C>` events.
+    pub trait AsyncEventPusher<T, C> {
+        /// Provides a new `Event<T, C>` to the pusher, completing once it has been accepted.
+        fn push<'a>(&'a mut self, event: Event<T, C>) -> Pin<Box<dyn Future<Output=::std::io::Result<()>>+'a>>;
+    }
+
+    /// A wrapper for `W: AsyncWrite` implementing `AsyncEventPusher<T, C>`.
+    ///
+    /// Events are written in the same format as `EventWriter`, and can be read by `EventReader`.
+    pub struct AsyncEventWriter<T, C, W: AsyncWrite+Unpin> {
+        stream: W,
+        buffer: Vec<u8>,
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    impl<T, C, W: AsyncWrite+Unpin> AsyncEventWriter<T, C, W> {
+        /// Allocates a new `AsyncEventWriter` wrapping a supplied writer.
+        pub fn new(w: W) -> Self {
+            Self {
+                stream: w,
+                buffer: Vec::new(),
+                phant: ::std::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, W: AsyncWrite+Unpin> AsyncEventWriter<T, C, W> {
+        /// Serializes and writes `event`, without blocking the runtime.
+        pub async fn push(&mut self, event: Event<T, C>) -> ::std::io::Result<()> {
+            self.buffer.clear();
+            unsafe { ::abomonation::encode(&event, &mut self.buffer)?; }
+            self.stream.write_all(&self.buffer[..]).await
+        }
+        /// Flushes the underlying writer.
+        pub async fn flush(&mut self) -> ::std::io::Result<()> {
+            self.stream.flush().await
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, W: AsyncWrite+Unpin> AsyncEventPusher<T, C> for AsyncEventWriter<T, C, W> {
+        fn push<'a>(&'a mut self, event: Event<T, C>) -> Pin<Box<dyn Future<Output=::std::io::Result<()>>+'a>> {
+            Box::pin(AsyncEventWriter::push(self, event))
+        }
+    }
+
+    // implementation for the synchronous end of a bridge to an async sink.
+    impl<T, C> EventPusher<T, C> for UnboundedSender<Event<T, C>> {
+        fn push(&mut self, event: Event<T, C>) {
+            // NOTE: An Err(x) result just means "data not accepted" most likely
+            //       because the receiver is gone. No need to panic.
+            let _ = self.send(event);
+        }
+    }
+
+    /// Forwards all events from `receiver` to `pusher`, until the senders are dropped or an error occurs.
+    pub async fn forward<T, C, P: AsyncEventPusher<T, C>>(mut receiver: UnboundedReceiver<Event<T, C>>, pusher: &mut P) -> ::std::io::Result<()> {
+        while let Some(event) = receiver.recv().await {
+            pusher.push(event).await?;
+        }
+        Ok(())
+    }
+
+    /// Polls `future` until it completes, for tests that need no runtime.
+    #[cfg(test)]
+    fn block_on<F: Future>(future: F) -> F::Output {
+        use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+        fn raw() -> RawWaker { RawWaker::new(::std::ptr::null(), &VTABLE) }
+        fn clone(_: *const ()) -> RawWaker { raw() }
+        fn noop(_: *const ()) { }
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        let waker = unsafe { Waker::from_raw(raw()) };
+        let mut context = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn forward_to_completion() {
+
+        use crate::dataflow::operators::{Capture, ToStream};
+        use crate::dataflow::operators::capture::{EventReader, Extract, Replay};
+
+        let (send, recv) = ::tokio::sync::mpsc::unbounded_channel();
+        crate::example(move |scope| {
+            (0 .. 10u64).to_stream(scope).capture_into(send);
+        });
+
+        // the sender is dropped with the dataflow, after which forwarding completes.
+        let mut writer = AsyncEventWriter::new(Vec::new());
+        block_on(forward(recv, &mut writer)).unwrap();
+        block_on(writer.flush()).unwrap();
+
+        let bytes = writer.stream;
+        let captured = crate::example(move |scope| {
+            Some(EventReader::<u64, Vec<u64>, _>::new(::std::io::Cursor::new(bytes)))
+                .replay_into(scope)
+                .capture()
+        });
+        assert_eq!(captured.extract().into_iter().flat_map(|x| x.1).collect::<Vec<_>>(), (0 .. 10).collect::<Vec<_>>());
+    }
+}
+
+/// A bounded queue of events, for capture and replay within a single thread.
+///
+/// Unlike `link::EventLink`, the number of unread events is capped. A pusher that finds the
+/// queue full can either receive the event back from `try_push`, signaling that it would block,
+/// or push it anyway through `EventPusher::push`, which evicts the oldest messages to make room.
+/// Progress events are never evicted, but are merged when only progress remains.
+pub mod bounded_link {
+
+    use std::rc::Rc;
+    use std::cell::{Cell, RefCell};
+    use std::collections::VecDeque;
+
+    use crate::progress::ChangeBatch;
+    use super::{Event, EventPusher, EventIterator};
+
+    /// A bounded single-threaded queue of `Event<T, C>`.
+    ///
+    /// Clones share the same queue, so that one clone can be used to push and another to iterate.
+    pub struct BoundedEventLink<T, C> {
+        queue: Rc<RefCell<VecDeque<Event<T, C>>>>,
+        depth: Rc<Cell<usize>>,
+        dropped: Rc<Cell<usize>>,
+        capacity: usize,
+        current: Option<Event<T, C>>,
+    }
+
+    impl<T, C> BoundedEventLink<T, C> {
+        /// Allocates a new `BoundedEventLink` holding at most `capacity` unread events.
+        pub fn new(capacity: usize) -> Self {
+            assert!(capacity > 0, "BoundedEventLink requires a positive capacity");
+            BoundedEventLink {
+                queue: Rc::new(RefCell::new(VecDeque::new())),
+                depth: Rc::new(Cell::new(0)),
+                dropped: Rc::new(Cell::new(0)),
+                capacity,
+                current: None,
+            }
+        }
+        /// The number of unread events.
+        pub fn depth(&self) -> usize { self.depth.get() }
+        /// The number of message events evicted to make room for newer events.
+        pub fn dropped(&self) -> usize { self.dropped.get() }
+        /// Returns true iff a further event would exceed the capacity.
+        pub fn is_full(&self) -> bool { self.depth() >= self.capacity }
+        /// Enqueues `event` if there is room, and otherwise returns it.
+        pub fn try_push(&mut self, event: Event<T, C>) -> Result<(), Event<T, C>> {
+            if self.is_full() {
+                Err(event)
+            }
+            else {
+                self.queue.borrow_mut().push_back(event);
+                self.depth.set(self.depth.get() + 1);
+                Ok(())
+            }
+        }
+    }
+
+    impl<T: Ord+Clone, C> BoundedEventLink<T, C> {
+        /// Removes events until fewer than `self.capacity` remain, preferring the oldest messages.
+        ///
+        /// Progress events are never discarded, as a replay requires them, but are coalesced
+        /// once only progress events remain. Returns false if there is still no room, which
+        /// happens when the capacity is one and the queue holds progress.
+        fn evict(&mut self) -> bool {
+            let mut queue = self.queue.borrow_mut();
+            while queue.len() >= self.capacity {
+                if let Some(position) = queue.iter().position(|event| matches!(event, Event::Messages(..))) {
+                    queue.remove(position);
+                    self.dropped.set(self.dropped.get() + 1);
+                }
+                else {
+                    // Only progress events remain; coalesce them into a single event.
+                    let mut progress = ChangeBatch::new();
+                    for event in queue.drain(..) {
+                        if let Event::Progress(updates) = event {
+                            progress.extend(updates.into_iter());
+                        }
+                    }
+                    queue.push_back(Event::Progress(progress.into_inner()));
+                    break;
+                }
+            }
+            self.depth.set(queue.len());
+            queue.len() < self.capacity
+        }
+    }
+
+    impl<T, C> Clone for BoundedEventLink<T, C> {
+        fn clone(&self) -> Self {
+            BoundedEventLink {
+                queue: self.queue.clone(),
+                depth: self.depth.clone(),
+                dropped: self.dropped.clone(),
+                capacity: self.capacity,
+                current: None,
+            }
+        }
+    }
+
+    impl<T: Ord+Clone, C> EventPusher<T, C> for BoundedEventLink<T, C> {
+        fn push(&mut self, event: Event<T, C>) {
+            if self.is_full() && !self.evict() {
+                // the queue is a single progress event, which absorbs progress and outranks messages.
+                match event {
+                    Event::Progress(updates) => {
+                        if let Some(Event::Progress(queued)) = self.queue.borrow_mut().back_mut() {
+                            let mut progress = ChangeBatch::new();
+                            progress.extend(queued.drain(..));
+                            progress.extend(updates.into_iter());
+                            *queued = progress.into_inner();
+                        }
+                    },
+                    Event::Messages(..) => {
+                        self.dropped.set(self.dropped.get() + 1);
+                    },
+                }
+                return;
+            }
+            self.queue.borrow_mut().push_back(event);
+            self.depth.set(self.depth.get() + 1);
+        }
+    }
+
+    impl<T, C> EventIterator<T, C> for BoundedEventLink<T, C> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            self.current = self.queue.borrow_mut().pop_front();
+            if self.current.is_some() {
+                self.depth.set(self.depth.get() - 1);
+            }
+            self.current.as_ref()
+        }
+    }
+
+    #[test]
+    fn evicts_oldest_messages() {
+        let mut link = BoundedEventLink::<u64, Vec<u64>>::new(2);
+        let mut reader = link.clone();
+        link.push(Event::Messages(0, vec![0]));
+        link.push(Event::Messages(1, vec![1]));
+        assert!(link.try_push(Event::Messages(2, vec![2])).is_err());
+        link.push(Event::Messages(2, vec![2]));
+        assert_eq!(link.depth(), 2);
+        assert_eq!(link.dropped(), 1);
+        assert!(matches!(reader.next(), Some(Event::Messages(1, _))));
+        assert!(matches!(reader.next(), Some(Event::Messages(2, _))));
+        assert!(reader.next().is_none());
+        assert_eq!(link.depth(), 0);
+    }
+
+    #[test]
+    fn unit_capacity() {
+        let mut link = BoundedEventLink::<u64, Vec<u64>>::new(1);
+        let mut reader = link.clone();
+        // a message makes way for progress.
+        link.push(Event::Messages(0, vec![0]));
+        link.push(Event::Progress(vec![(0, -1), (1, 1)]));
+        assert_eq!(link.dropped(), 1);
+        // progress is not displaced by a message, and absorbs further progress.
+        link.push(Event::Messages(1, vec![1]));
+        link.push(Event::Progress(vec![(1, -1), (2, 1)]));
+        assert_eq!(link.depth(), 1);
+        assert_eq!(link.dropped(), 2);
+        assert_eq!(reader.next(), Some(&Event::Progress(vec![(0, -1), (2, 1)])));
+        assert!(reader.next().is_none());
+        link.push(Event::Messages(2, vec![2]));
+        assert_eq!(reader.next(), Some(&Event::Messages(2, vec![2])));
+        assert_eq!(link.depth(), 0);
+    }
+}
+
+/// Resumption of replay from a timestamp.
+pub mod seek {
+
+    use crate::progress::ChangeBatch;
+    use super::{Event, EventIterator};
+
+    /// Wraps an `EventIterator`, skipping events until it reaches a timestamp.
+    ///
+    /// Messages at times strictly less than the target time are discarded. Progress events are
+    /// not discarded, but are accumulated and yielded as one event once the seek completes, so that
+    /// the replayed frontier is consistent with the full history. The seek completes at the first
+    /// message at or beyond the target time, or the first progress event mentioning such a time.
+    pub struct SeekEventIterator<T: Ord+Clone, C, I: EventIterator<T, C>> {
+        iterator: I,
+        time: T,
+        seeking: bool,
+        skipped: ChangeBatch<T>,
+        pending: Option<Event<T, C>>,
+        current: Option<Event<T, C>>,
+    }
+
+    impl<T: Ord+Clone, C, I: EventIterator<T, C>> SeekEventIterator<T, C, I> {
+        /// Allocates a new iterator that skips the events of `iterator` before `time`.
+        pub fn new(iterator: I, time: T) -> Self {
+            SeekEventIterator {
+                iterator,
+                time,
+                seeking: true,
+                skipped: ChangeBatch::new(),
+                pending: None,
+                current: None,
+            }
+        }
+        /// Includes progress `updates` that precede the events of the wrapped iterator.
+        ///
+        /// This is required when the wrapped iterator does not start at the beginning of the
+        /// capture, for example after `EventReader::seek`, and the updates should be the
+        /// accumulated progress of the events before that point.
+        pub fn with_progress(mut self, updates: Vec<(T, i64)>) -> Self {
+            self.skipped.extend(updates.into_iter());
+            self
+        }
+        /// Returns true iff the seek has completed.
+        pub fn done(&self) -> bool { !self.seeking }
+    }
+
+    impl<T: Ord+Clone, C: Clone, I: EventIterator<T, C>> EventIterator<T, C> for SeekEventIterator<T, C, I> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+
+            if let Some(event) = self.pending.take() {
+                self.current = Some(event);
+                return self.current.as_ref();
+            }
+
+            if !self.seeking {
+                return self.iterator.next();
+            }
+
+            loop {
+                match self.iterator.next()?.clone() {
+                    Event::Progress(updates) => {
+                        let reached = updates.iter().any(|(time, _)| time >= &self.time);
+                        self.skipped.extend(updates.into_iter());
+                        if reached {
+                            self.seeking = false;
+                            let skipped = ::std::mem::replace(&mut self.skipped, ChangeBatch::new());
+                            self.current = Some(Event::Progress(skipped.into_inner()));
+                            return self.current.as_ref();
+                        }
+                    },
+                    Event::Messages(time, data) => {
+                        if time >= self.time {
+                            self.seeking = false;
+                            let skipped = ::std::mem::replace(&mut self.skipped, ChangeBatch::new()).into_inner();
+                            if skipped.is_empty() {
+                                self.current = Some(Event::Messages(time, data));
+                            }
+                            else {
+                                self.pending = Some(Event::Messages(time, data));
+                                self.current = Some(Event::Progress(skipped));
+                            }
+                            return self.current.as_ref();
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn skips_messages_and_retains_progress() {
+
+        let events = vec![
+            Event::Progress(vec![(0, -1), (1, 1)]),
+            Event::Messages(1, vec![1]),
+            Event::Progress(vec![(1, -1), (2, 1)]),
+            Event::Messages(2, vec![2]),
+        ];
+
+        let mut link = ::std::rc::Rc::new(super::link::EventLink::<u64, Vec<u64>>::new());
+        let mut iterator = SeekEventIterator::new(link.clone(), 2);
+        for event in events {
+            super::EventPusher::push(&mut link, event);
+        }
+
+        match iterator.next() {
+            Some(Event::Progress(updates)) => assert_eq!(updates, &vec![(0, -1), (2, 1)]),
+            other => panic!("expected progress, found {:?}", other.is_some()),
+        }
+        assert!(matches!(iterator.next(), Some(Event::Messages(2, _))));
+        assert!(iterator.done());
+        assert!(iterator.next().is_none());
+    }
+}
+
+/// Partial replay of captured events within a range of timestamps.
+pub mod range {
+
+    use crate::order::PartialOrder;
+    use crate::progress::{ChangeBatch, Timestamp};
+    use super::{Event, EventIterator};
+
+    /// Wraps an `EventIterator`, yielding only the messages at timestamps in the range `[lo, hi)`.
+    ///
+    /// A timestamp is in the range if it is greater or equal to `lo`, and not greater or equal to
+    /// `hi`. Messages at other timestamps are discarded. Progress updates are trimmed to the range,
+    /// so that the replayed frontier is consistent with the retained messages: updates at times
+    /// before `lo` are moved to `lo`, and updates at times at or beyond `hi` are moved to `hi`.
+    /// Updates at `Timestamp::minimum()` are retained, as they retract the initial capability of
+    /// the replaying operator. Progress events with no updates left after trimming are discarded.
+    pub struct RangeEventIterator<T: Timestamp, C, I: EventIterator<T, C>> {
+        iterator: I,
+        lo: T,
+        hi: T,
+        current: Option<Event<T, C>>,
+    }
+
+    impl<T: Timestamp, C, I: EventIterator<T, C>> RangeEventIterator<T, C, I> {
+        /// Allocates a new iterator yielding the events of `iterator` in the range `[lo, hi)`.
+        pub fn new(iterator: I, lo: T, hi: T) -> Self {
+            RangeEventIterator {
+                iterator,
+                lo,
+                hi,
+                current: None,
+            }
+        }
+        /// Returns the wrapped iterator.
+        pub fn into_inner(self) -> I { self.iterator }
+    }
+
+    impl<T: Timestamp, C: Clone, I: EventIterator<T, C>> EventIterator<T, C> for RangeEventIterator<T, C, I> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            loop {
+                match self.iterator.next()? {
+                    Event::Messages(time, data) => {
+                        if self.lo.less_equal(time) && !self.hi.less_equal(time) {
+                            self.current = Some(Event::Messages(time.clone(), data.clone()));
+                            return self.current.as_ref();
+                        }
+                    },
+                    Event::Progress(updates) => {
+                        let mut trimmed = ChangeBatch::new();
+                        for (time, diff) in updates.iter() {
+                            let time = if self.hi.less_equal(time) {
+                                self.hi.clone()
+                            }
+                            else if !self.lo.less_equal(time) && time != &T::minimum() {
+                                self.lo.clone()
+                            }
+                            else {
+                                time.clone()
+                            };
+                            trimmed.update(time, *diff);
+                        }
+                        if !trimmed.is_empty() {
+                            self.current = Some(Event::Progress(trimmed.into_inner()));
+                            return self.current.as_ref();
+                        }
+                    },
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn trims_to_range() {
+
+        let events = vec![
+            Event::Progress(vec![(0, -1), (1, 1)]),
+            Event::Messages(1, vec![1]),
+            Event::Progress(vec![(1, -1), (2, 1)]),
+            Event::Messages(2, vec![2]),
+            Event::Progress(vec![(2, -1), (3, 1)]),
+            Event::Messages(3, vec![3]),
+            Event::Progress(vec![(3, -1)]),
+        ];
+
+        let mut link = ::std::rc::Rc::new(super::link::EventLink::<u64, Vec<u64>>::new());
+        let mut iterator = RangeEventIterator::new(link.clone(), 2, 3);
+        for event in events {
+            super::EventPusher::push(&mut link, event);
+        }
+
+        let mut replayed = Vec::new();
+        while let Some(event) = iterator.next() {
+            replayed.push(event.clone());
+        }
+        assert_eq!(replayed, vec![
+            Event::Progress(vec![(0, -1), (2, 1)]),
+            Event::Messages(2, vec![2]),
+            Event::Progress(vec![(2, -1), (3, 1)]),
+            Event::Progress(vec![(3, -1)]),
+        ]);
+    }
+}
+
+/// Paced replay of captured events.
+pub mod throttle {
+
+    use std::time::{Duration, Instant};
+    use abomonation::Abomonation;
+    use super::{Event, EventIterator};
+
+    /// Wraps an `EventIterator`, limiting the rate at which it yields events.
+    ///
+    /// Each event has a cost, either one per event or its serialized size in bytes, and the iterator
+    /// sleeps as needed so that the total cost yielded never exceeds `per_second` times the time since
+    /// the first event. Because the schedule is measured from a fixed start rather than between calls,
+    /// time spent by the caller processing events is credited, and does not cause over-sleeping.
+    ///
+    /// Sleeping blocks the calling thread, which for `replay_into` is the timely worker.
+    pub struct ThrottledEventIterator<T, C, I: EventIterator<T, C>> {
+        iterator: I,
+        per_second: u64,
+        cost: fn(&Event<T, C>) -> u64,
+        start: Option<Instant>,
+        spent: u64,
+    }
+
+    impl<T, C, I: EventIterator<T, C>> ThrottledEventIterator<T, C, I> {
+        /// Allocates a new iterator yielding at most `per_second` events each second.
+        pub fn events_per_second(iterator: I, per_second: u64) -> Self {
+            assert!(per_second > 0, "ThrottledEventIterator requires a positive rate");
+            ThrottledEventIterator {
+                iterator,
+                per_second,
+                cost: |_event| 1,
+                start: None,
+                spent: 0,
+            }
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation, I: EventIterator<T, C>> ThrottledEventIterator<T, C, I> {
+        /// Allocates a new iterator yielding at most `per_second` serialized bytes each second.
+        pub fn bytes_per_second(iterator: I, per_second: u64) -> Self {
+            assert!(per_second > 0, "ThrottledEventIterator requires a positive rate");
+            ThrottledEventIterator {
+                iterator,
+                per_second,
+                cost: |event| ::abomonation::measure(event) as u64,
+                start: None,
+                spent: 0,
+            }
+        }
+    }
+
+    impl<T, C, I: EventIterator<T, C>> EventIterator<T, C> for ThrottledEventIterator<T, C, I> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            let start = *self.start.get_or_insert_with(Instant::now);
+            let due = start + Duration::from_secs_f64(self.spent as f64 / self.per_second as f64);
+            let now = Instant::now();
+            if due > now {
+                ::std::thread::sleep(due - now);
+            }
+            let event = self.iterator.next()?;
+            self.spent += (self.cost)(event);
+            Some(event)
+        }
+    }
+
+    #[test]
+    fn limits_event_rate() {
+
+        use std::rc::Rc;
+        use super::{EventPusher, link::EventLink};
+
+        let mut link = Rc::new(EventLink::<u64, Vec<u64>>::new());
+        let mut iterator = ThrottledEventIterator::events_per_second(link.clone(), 100);
+        for round in 0 .. 11 {
+            link.push(Event::Messages(round, vec![round]));
+        }
+
+        let timer = Instant::now();
+        let mut count = 0;
+        while iterator.next().is_some() { count += 1; }
+        assert_eq!(count, 11);
+        // the eleventh event is due 100ms after the first.
+        assert!(timer.elapsed() >= Duration::from_millis(100));
+    }
+}
+
+/// A memory-mapped ring buffer of events, for capture between processes on one machine.
+///
+/// The ring buffer is a file holding a header of two `u64` positions, `head` and `tail`,
+/// followed by a data region. Each event is written as a `u32` little-endian length followed
+/// by its serialized bytes, wrapping around the end of the data region as needed. Positions
+/// only increase, and are reduced modulo the length of the data region.
+///
+/// The writer advances `head` only once a record is completely written, and the reader advances
+/// `tail` only once it has copied a record out, so neither observes a partially written record.
+/// There should be at most one writer and one reader of each ring buffer.
+#[cfg(feature = "memmap2")]
+pub mod mmap {
+
+    use std::fs::OpenOptions;
+    use std::io::{Error, ErrorKind, Result};
+    use std::path::Path;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use abomonation::Abomonation;
+    use memmap2::MmapMut;
+    use super::{Event, EventPusher, EventIterator, TryEventPusher, EventError};
+
+    /// The length in bytes of the header preceding the data region.
+    const HEADER: usize = 16;
+    /// The length in bytes of the length preceding each record.
+    const PREFIX: usize = 4;
+    /// The index in the header of the position up to which records are written.
+    const HEAD: usize = 0;
+    /// The index in the header of the position up to which records are read.
+    const TAIL: usize = 1;
+
+    /// Loads a position from the header of `map`.
+    fn load(map: &MmapMut, index: usize, order: Ordering) -> u64 {
+        // Mappings are page aligned, and so suitably aligned for `AtomicU64`.
+        unsafe { (*(map.as_ptr() as *const AtomicU64).add(index)).load(order) }
+    }
+
+    /// Stores a position into the header of `map`.
+    fn store(map: &MmapMut, index: usize, value: u64, order: Ordering) {
+        unsafe { (*(map.as_ptr() as *const AtomicU64).add(index)).store(value, order) }
+    }
+
+    /// Copies `bytes` into `data` at `position`, wrapping around the end of `data`.
+    fn write_at(data: &mut [u8], position: u64, bytes: &[u8]) {
+        let offset = (position % data.len() as u64) as usize;
+        let first = ::std::cmp::min(bytes.len(), data.len() - offset);
+        data[offset .. offset + first].copy_from_slice(&bytes[.. first]);
+        data[.. bytes.len() - first].copy_from_slice(&bytes[first ..]);
+    }
+
+    /// Copies from `data` at `position` into `bytes`, wrapping around the end of `data`.
+    fn read_at(data: &[u8], position: u64, bytes: &mut [u8]) {
+        let offset = (position % data.len() as u64) as usize;
+        let first = ::std::cmp::min(bytes.len(), data.len() - offset);
+        let length = bytes.len();
+        bytes[.. first].copy_from_slice(&data[offset .. offset + first]);
+        bytes[first ..].copy_from_slice(&data[.. length - first]);
+    }
+
+    /// Writes events into a memory-mapped ring buffer.
+    ///
+    /// When the ring buffer is full the writer spins until the reader frees enough space,
+    /// which blocks the worker if no reader is attached.
+    pub struct MmapEventPusher<T, C> {
+        map: MmapMut,
+        buffer: Vec<u8>,
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    impl<T, C> MmapEventPusher<T, C> {
+        /// Creates a ring buffer at `path` with a data region of `capacity` bytes.
+        ///
+        /// Any existing file at `path` is replaced. Each serialized event, with its length,
+        /// must fit within `capacity` bytes.
+        pub fn create<P: AsRef<Path>>(path: P, capacity: usize) -> Result<Self> {
+            if capacity <= PREFIX {
+                return Err(Error::new(ErrorKind::InvalidInput, "ring buffer capacity too small"));
+            }
+            let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+            // A newly extended file is zeroed, and so both positions start at zero.
+            file.set_len((HEADER + capacity) as u64)?;
+            let map = unsafe { MmapMut::map_mut(&file)? };
+            Ok(Self {
+                map,
+                buffer: Vec::new(),
+                phant: ::std::marker::PhantomData,
+            })
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation> TryEventPusher<T, C> for MmapEventPusher<T, C> {
+        fn try_push(&mut self, event: Event<T, C>) -> ::std::result::Result<(), EventError> {
+            self.buffer.clear();
+            self.buffer.extend_from_slice(&[0u8; PREFIX]);
+            unsafe { ::abomonation::encode(&event, &mut self.buffer) }
+                .map_err(|error| EventError::Serialization(error.to_string()))?;
+            let capacity = (self.map.len() - HEADER) as u64;
+            let length = self.buffer.len() - PREFIX;
+            if self.buffer.len() as u64 > capacity || length > u32::MAX as usize {
+                return Err(Error::new(ErrorKind::InvalidInput, "event exceeds ring buffer capacity").into());
+            }
+            self.buffer[.. PREFIX].copy_from_slice(&(length as u32).to_le_bytes());
+
+            // Only this writer advances `head`, and the reader only advances `tail`.
+            let head = load(&self.map, HEAD, Ordering::Relaxed);
+            while capacity - (head - load(&self.map, TAIL, Ordering::Acquire)) < self.buffer.len() as u64 {
+                ::std::thread::yield_now();
+            }
+            write_at(&mut self.map[HEADER ..], head, &self.buffer[..]);
+            store(&self.map, HEAD, head + self.buffer.len() as u64, Ordering::Release);
+            Ok(())
+        }
+    }
+
+    impl<T: Abomonation, C: Abomonation> EventPusher<T, C> for MmapEventPusher<T, C> {
+        fn push(&mut self, event: Event<T, C>) {
+            // TODO: `push` has no mechanism to report errors, so we `unwrap`.
+            self.try_push(event).expect("Event serialization/write failed");
+        }
+    }
+
+    /// Reads events from a memory-mapped ring buffer.
+    ///
+    /// The reader does not block: `next` returns `None` when no complete record is available,
+    /// and replay will ask again when next scheduled. A record is only released to the writer
+    /// once it has been decoded; a record that cannot be decoded stops the reader, leaving the
+    /// record in place and the cause in `error`.
+    pub struct MmapEventReader<T, C> {
+        map: MmapMut,
+        buffer: Vec<u8>,
+        error: Option<EventError>,
+        phant: ::std::marker::PhantomData<(T, C)>,
+    }
+
+    impl<T, C> MmapEventReader<T, C> {
+        /// Opens the ring buffer at `path`, created by `MmapEventPusher::create`.
+        pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+            let file = OpenOptions::new().read(true).write(true).open(path)?;
+            let map = unsafe { MmapMut::map_mut(&file)? };
+            if map.len() <= HEADER + PREFIX {
+                return Err(Error::new(ErrorKind::InvalidData, "file is too short to be a ring buffer"));
+            }
+            Ok(Self {
+                map,
+                buffer: Vec::new(),
+                error: None,
+                phant: ::std::marker::PhantomData,
+            })
+        }
+        /// The error that stopped the reader, if any.
+        ///
+        /// Once an error is recorded `next` returns `None`, which can be distinguished from a
+        /// lack of data by consulting this method.
+        pub fn error(&self) -> Option<&EventError> { self.error.as_ref() }
+    }
+
+    impl<T: Abomonation, C: Abomonation> EventIterator<T, C> for MmapEventReader<T, C> {
+        fn next(&mut self) -> Option<&Event<T, C>> {
+            if self.error.is_some() {
+                return None;
+            }
+            let head = load(&self.map, HEAD, Ordering::Acquire);
+            let tail = load(&self.map, TAIL, Ordering::Relaxed);
+            // `head` only advances past complete records, so any record before it is complete.
+            if head == tail {
+                return None;
+            }
+            let mut length = [0u8; PREFIX];
+            read_at(&self.map[HEADER ..], tail, &mut length);
+            let length = u32::from_le_bytes(length) as u64;
+            let capacity = (self.map.len() - HEADER) as u64;
+            if length > capacity - PREFIX as u64 || length + PREFIX as u64 > head - tail {
+                self.error = Some(EventError::Serialization("record length exceeds the written region".to_owned()));
+                return None;
+            }
+            self.buffer.clear();
+            self.buffer.resize(length as usize, 0);
+            read_at(&self.map[HEADER ..], tail + PREFIX as u64, &mut self.buffer[..]);
+
+            // the record is released to the writer only once decoded, as a failure leaves it in place.
+            match unsafe { ::abomonation::decode::<Event<T, C>>(&mut self.buffer[..]) } {
+                Some((event, rest)) if rest.is_empty() => {
+                    store(&self.map, TAIL, tail + PREFIX as u64 + length, Ordering::Release);
+                    Some(event)
+                },
+                _ => {
+                    self.error = Some(EventError::Serialization("record failed to decode".to_owned()));
+                    None
+                },
+            }
+        }
+    }
+
+    #[test]
+    fn wraps_around() {
+
+        let path = ::std::env::temp_dir().join(format!("timely-mmap-wraps-{}", ::std::process::id()));
+        let mut writer = MmapEventPusher::<u64, Vec<u64>>::create(&path, 256).unwrap();
+        let mut reader = MmapEventReader::<u64, Vec<u64>>::open(&path).unwrap();
+
+        assert!(reader.next().is_none());
+        for round in 0 .. 20 {
+            writer.push(Event::Messages(round, vec![round, round + 1]));
+            writer.push(Event::Progress(vec![(round, 1)]));
+            assert_eq!(reader.next(), Some(&Event::Messages(round, vec![round, round + 1])));
+            assert_eq!(reader.next(), Some(&Event::Progress(vec![(round, 1)])));
+            assert!(reader.next().is_none());
+        }
+
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn corrupt_length_reports_error() {
+
+        let path = ::std::env::temp_dir().join(format!("timely-mmap-length-{}", ::std::process::id()));
+        let mut writer = MmapEventPusher::<u64, Vec<u64>>::create(&path, 256).unwrap();
+        let mut reader = MmapEventReader::<u64, Vec<u64>>::open(&path).unwrap();
+
+        writer.push(Event::Messages(0, vec![0, 1]));
+        writer.map[HEADER .. HEADER + PREFIX].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(reader.next().is_none());
+        assert!(reader.error().is_some());
+        // the record remains unread.
+        assert_eq!(load(&reader.map, TAIL, Ordering::Relaxed), 0);
+
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn bad_payload_reports_error() {
+
+        let path = ::std::env::temp_dir().join(format!("timely-mmap-payload-{}", ::std::process::id()));
+        let mut writer = MmapEventPusher::<u64, Vec<u64>>::create(&path, 256).unwrap();
+        let mut reader = MmapEventReader::<u64, Vec<u64>>::open(&path).unwrap();
+
+        writer.push(Event::Messages(0, vec![0, 1]));
+        // claim a shorter record, which omits the contents of the vector.
+        let mut length = [0u8; PREFIX];
+        length.copy_from_slice(&writer.map[HEADER .. HEADER + PREFIX]);
+        let length = u32::from_le_bytes(length) - 16;
+        writer.map[HEADER .. HEADER + PREFIX].copy_from_slice(&length.to_le_bytes());
+        assert!(reader.next().is_none());
+        assert!(reader.error().is_some());
+        assert_eq!(load(&reader.map, TAIL, Ordering::Relaxed), 0);
+
+        ::std::fs::remove_file(&path).unwrap();
+    }
 }
@@ -76,10 +76,30 @@
 pub use self::capture::Capture;
 pub use self::replay::Replay;
 pub use self::extract::Extract;
-pub use self::event::{Event, EventPusher};
+pub use self::event::{Event, EventPusher, TryEventPusher, EventError, TeeEventPusher};
+pub use self::event::{FilterEventPusher, MapEventPusher, CoalescingEventPusher};
+pub use self::event::OwnedEvents;
+pub use self::event::{WorkerTaggingEventPusher, WorkerTaggedEventIterator};
 pub use self::event::link::EventLink;
+pub use self::event::bounded_link::BoundedEventLink;
 pub use self::event::binary::EventReader;
 pub use self::event::binary::EventWriter;
+pub use self::event::binary::{EventCodec, AbomonationCodec, TimedReplayIterator};
+pub use self::event::binary::{DurableEventWriter, SyncPolicy};
+pub use self::event::binary::{SizingEventWriter, EventSizes};
+pub use self::event::binary::{RotatingEventWriter, RotatingEventReader, RotationPolicy};
+pub use self::event::framed::{FramedEventReader, FramedEventWriter};
+pub use self::event::compressed::{CompressedEventReader, CompressedEventWriter};
+pub use self::event::merge::MergeEventIterator;
+pub use self::event::columnar::{ColumnarEventReader, ColumnarEventWriter};
+pub use self::event::tcp::ReconnectingTcpEventPusher;
+pub use self::event::seek::SeekEventIterator;
+pub use self::event::range::RangeEventIterator;
+pub use self::event::throttle::ThrottledEventIterator;
+#[cfg(feature = "tokio")]
+pub use self::event::asynchronous::{AsyncEventPusher, AsyncEventWriter};
+#[cfg(feature = "memmap2")]
+pub use self::event::mmap::{MmapEventPusher, MmapEventReader};
 
 pub mod capture;
 pub mod replay;
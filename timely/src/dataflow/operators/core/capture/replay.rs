@@ -51,14 +51,55 @@ use crate::Container;
 /// Replay a capture stream into a scope with the same timestamp.
 pub trait Replay<T: Timestamp, C> : Sized {
     /// Replays `self` into the provided scope, as a `StreamCore<S, C>`.
+    ///
+    /// The replay operator reconstructs the capabilities of the captured stream, so that the
+    /// replayed stream has the same frontier as the original, and downstream operators observe
+    /// the same progress. Each `Event::Progress` is applied to the capabilities the operator
+    /// holds, and each `Event::Messages` is sent at its time, in the order the events are read.
+    ///
+    /// The operator starts with a single capability for `Timestamp::minimum()`, as the captured
+    /// operator's input did. Each event stream accounts for one such capability, which its first
+    /// progress event releases, and so on its first invocation the operator adjusts its initial
+    /// capability to one for each event stream, as `probe_with` discards its initial capability.
+    ///
+    /// The operator re-activates itself after each invocation, to read events as they become
+    /// available. Use `replay_core` to control or disable re-activation.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use timely::dataflow::operators::{Capture, Input, Probe};
+    /// use timely::dataflow::operators::capture::{EventLink, Replay};
+    ///
+    /// timely::execute_directly(|worker| {
+    ///     let link = Rc::new(EventLink::new());
+    ///     let replay = Some(link.clone());
+    ///
+    ///     let mut input = worker.dataflow::<u64,_,_>(|scope| {
+    ///         let (input, stream) = scope.new_input::<u64>();
+    ///         stream.capture_into(link);
+    ///         input
+    ///     });
+    ///     let probe = worker.dataflow::<u64,_,_>(|scope| {
+    ///         replay.replay_into(scope).probe()
+    ///     });
+    ///
+    ///     // the replayed frontier follows the captured frontier.
+    ///     input.send(0);
+    ///     input.advance_to(3);
+    ///     worker.step_while(|| probe.less_than(&3));
+    ///     assert!(!probe.less_than(&3));
+    /// });
+    /// ```
     fn replay_into<S: Scope<Timestamp=T>>(self, scope: &mut S) -> StreamCore<S, C> {
         self.replay_core(scope, Some(std::time::Duration::new(0, 0)))
     }
-    /// Replays `self` into the provided scope, as a `StreamCore<S, C>'.
+    /// Replays `self` into the provided scope, as a `StreamCore<S, C>`.
     ///
     /// The `period` argument allows the specification of a re-activation period, where the operator
     /// will re-activate itself every so often. The `None` argument instructs the operator not to
-    /// re-activate itself.us
+    /// re-activate itself, in which case it reads new events only when otherwise scheduled.
+    /// Capabilities are reconstructed as described for `replay_into`.
     fn replay_core<S: Scope<Timestamp=T>>(self, scope: &mut S, period: Option<std::time::Duration>) -> StreamCore<S, C>;
 }
 
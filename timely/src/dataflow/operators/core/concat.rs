@@ -33,6 +33,11 @@ impl<G: Scope, C: Container> Concat<G, C> for StreamCore<G, C> {
 pub trait Concatenate<G: Scope, C: Container> {
     /// Merge the contents of multiple streams.
     ///
+    /// All streams are merged by a single operator with one input for each stream, rather than
+    /// by a chain of binary `concat` operators. Data are forwarded at the times they were received,
+    /// and the output frontier is the meet of the input frontiers, so that a time is complete in
+    /// the output only once it is complete in every input.
+    ///
     /// # Examples
     /// ```
     /// use timely::dataflow::operators::{ToStream, Concatenate, Inspect};
@@ -96,3 +101,33 @@ impl<G: Scope, C: Container> Concatenate<G, C> for G {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+
+    use crate::dataflow::operators::{Input, Probe};
+    use super::Concatenate;
+
+    #[test]
+    fn frontier_awaits_all_inputs() {
+
+        crate::execute_directly(|worker| {
+            let (mut inputs, probe) = worker.dataflow::<u64, _, _>(|scope| {
+                let (inputs, streams): (Vec<_>, Vec<_>) = (0 .. 3).map(|_| scope.new_input::<u64>()).unzip();
+                (inputs, scope.concatenate(streams).probe())
+            });
+
+            // advancing all but one input must not advance the merged frontier.
+            for input in inputs.iter_mut().skip(1) {
+                input.advance_to(1);
+            }
+            worker.step();
+            worker.step();
+            assert!(probe.less_equal(&0));
+
+            inputs[0].advance_to(1);
+            worker.step_while(|| probe.less_than(&1));
+            assert!(!probe.less_equal(&0));
+        });
+    }
+}
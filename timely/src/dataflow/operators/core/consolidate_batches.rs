@@ -0,0 +1,101 @@
+//! Combine the containers of a stream at each timestamp.
+
+use crate::container::{Container, CapacityContainerBuilder, PushInto};
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::InputCapability;
+use crate::dataflow::operators::generic::{Operator, CapabilityStash};
+use crate::dataflow::{Scope, StreamCore};
+
+/// Combine the containers of a stream at each timestamp.
+pub trait ConsolidateBatches<G: Scope, C: Container> {
+    /// Combines the containers received at each time into one container, sent once the time
+    /// is complete.
+    ///
+    /// This consolidates containers, rather than data: records are moved into a container for
+    /// their time, but are neither sorted nor combined. To bound memory, a container holding at
+    /// least `limit` records is sent immediately, and the time continues with a new container.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Concatenate, Inspect};
+    /// use timely::dataflow::operators::core::ConsolidateBatches;
+    ///
+    /// timely::example(|scope| {
+    ///     let streams = vec![(0..5).to_stream(scope), (5..10).to_stream(scope)];
+    ///     scope.concatenate(streams)
+    ///          .consolidate_batches(1024)
+    ///          .inspect_batch(|_t, xs| assert_eq!(xs.len(), 10));
+    /// });
+    /// ```
+    fn consolidate_batches(&self, limit: usize) -> StreamCore<G, C>;
+}
+
+impl<G: Scope, C> ConsolidateBatches<G, C> for StreamCore<G, C>
+where
+    C: Container + for<'a> PushInto<C::Item<'a>>,
+{
+    fn consolidate_batches(&self, limit: usize) -> StreamCore<G, C> {
+        let mut stash = CapabilityStash::<G::Timestamp, C>::new();
+        let mut buffer = C::default();
+        self.unary_frontier::<CapacityContainerBuilder<C>, _, _, _>(Pipeline, "ConsolidateBatches", move |_, _| move |input, output| {
+            input.for_each(|cap, data| {
+                data.swap(&mut buffer);
+                let (cap, container) = stash.entry(cap, InputCapability::retain);
+                for item in buffer.drain() {
+                    container.push(item);
+                }
+                if container.len() >= limit {
+                    output.session(cap).give_container(container);
+                    container.clear();
+                }
+            });
+
+            // send the containers of completed times, in order of time.
+            stash.for_each(&[input.frontier()], |cap, mut container| {
+                if !container.is_empty() {
+                    output.session(&cap).give_container(&mut container);
+                }
+            });
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::dataflow::operators::{Input, Probe};
+    use crate::dataflow::operators::capture::{Capture, Event};
+    use super::ConsolidateBatches;
+
+    #[test]
+    fn combined_once_complete() {
+
+        let (held, captured) = crate::execute_directly(|worker| {
+            let (mut input, probe, captured) = worker.dataflow::<u64, _, _>(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                let consolidated = stream.consolidate_batches(4);
+                (input, consolidated.probe(), consolidated.capture())
+            });
+            input.send_batch(&mut vec![0, 1]);
+            input.send_batch(&mut vec![2]);
+            input.advance_to(1);
+            input.send_batch(&mut vec![3, 4, 5]);
+            input.send_batch(&mut vec![6, 7]);
+            input.send_batch(&mut vec![8]);
+            worker.step();
+            worker.step();
+            // time 1 is incomplete, so its remaining records must not have been sent.
+            let held = probe.less_equal(&1);
+            input.close();
+            while worker.step() { }
+            (held, captured)
+        });
+
+        let batches = captured.try_iter().filter_map(|event| match event {
+            Event::Messages(time, data) => Some((time, data)),
+            Event::Progress(_) => None,
+        }).collect::<Vec<_>>();
+        assert!(held);
+        assert_eq!(batches, vec![(0, vec![0, 1, 2]), (1, vec![3, 4, 5, 6, 7]), (1, vec![8])]);
+    }
+}
@@ -0,0 +1,84 @@
+//! Hold the containers of a stream until their times are complete.
+
+use crate::container::CapacityContainerBuilder;
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::InputCapability;
+use crate::dataflow::operators::generic::{Operator, CapabilityStash};
+use crate::dataflow::{Scope, StreamCore};
+use crate::Container;
+
+/// Hold the containers of a stream until their times are complete.
+pub trait DelayUntilComplete<G: Scope, C: Container> {
+    /// Holds each received container until its time is complete, then sends it in time order.
+    ///
+    /// A container is retained, with a capability for its time, until the input frontier no
+    /// longer contains a time less or equal to it. The capability is dropped once the container
+    /// is sent, so the output frontier trails the input frontier only while data is held.
+    /// Containers are sent unmodified; downstream operators may rely on receiving all data for
+    /// a time before the time is complete, which makes this a building block for windowing.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::core::DelayUntilComplete;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .delay_until_complete()
+    ///            .inspect(|x| println!("seen: {:?}", x));
+    /// });
+    /// ```
+    fn delay_until_complete(&self) -> StreamCore<G, C>;
+}
+
+impl<G: Scope, C: Container> DelayUntilComplete<G, C> for StreamCore<G, C> {
+    fn delay_until_complete(&self) -> StreamCore<G, C> {
+        let mut stash = CapabilityStash::<G::Timestamp, Vec<C>>::new();
+        self.unary_frontier::<CapacityContainerBuilder<C>, _, _, _>(Pipeline, "DelayUntilComplete", move |_, _| move |input, output| {
+            input.for_each(|cap, data| {
+                stash.entry(cap, InputCapability::retain).1.push(data.take());
+            });
+
+            // send the containers of completed times, in order of time, releasing their capabilities.
+            stash.for_each(&[input.frontier()], |cap, containers| {
+                let mut session = output.session(&cap);
+                for mut container in containers {
+                    session.give_container(&mut container);
+                }
+            });
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::dataflow::operators::{Input, Probe};
+    use crate::dataflow::operators::capture::{Capture, Extract};
+    use super::DelayUntilComplete;
+
+    #[test]
+    fn held_until_complete() {
+
+        let (held, captured) = crate::execute_directly(|worker| {
+            let (mut input, probe, captured) = worker.dataflow::<u64, _, _>(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                let delayed = stream.delay_until_complete();
+                (input, delayed.probe(), delayed.capture())
+            });
+            input.send(0);
+            input.advance_to(1);
+            input.send(1);
+            worker.step();
+            worker.step();
+            // time 1 is incomplete, so the output must not have advanced past it.
+            let held = probe.less_equal(&1);
+            input.close();
+            while worker.step() { }
+            (held, captured)
+        });
+
+        assert!(held);
+        assert_eq!(captured.extract(), vec![(0, vec![0]), (1, vec![1])]);
+    }
+}
@@ -142,7 +142,9 @@ impl<TOuter: Timestamp, TInner: Timestamp+Refines<TOuter>, TContainer: Container
         if let Some(message) = element {
             let outer_message = message.as_mut();
             let data = ::std::mem::take(&mut outer_message.data);
-            let mut inner_message = Some(Bundle::from_typed(Message::new(TInner::to_inner(outer_message.time.clone()), data, 0, 0)));
+            let mut inner_message = Message::new(TInner::to_inner(outer_message.time.clone()), data, 0, 0);
+            inner_message.tag = outer_message.tag;
+            let mut inner_message = Some(Bundle::from_typed(inner_message));
             self.targets.push(&mut inner_message);
             if let Some(inner_message) = inner_message {
                 if let Some(inner_message) = inner_message.if_typed() {
@@ -173,7 +175,9 @@ where TOuter: Timestamp, TInner: Timestamp+Refines<TOuter>, TContainer: Data {
         if let Some(message) = message {
             let inner_message = message.as_mut();
             let data = ::std::mem::take(&mut inner_message.data);
-            let mut outer_message = Some(Bundle::from_typed(Message::new(inner_message.time.clone().to_outer(), data, 0, 0)));
+            let mut outer_message = Message::new(inner_message.time.clone().to_outer(), data, 0, 0);
+            outer_message.tag = inner_message.tag;
+            let mut outer_message = Some(Bundle::from_typed(outer_message));
             self.targets.push(&mut outer_message);
             if let Some(outer_message) = outer_message {
                 if let Some(outer_message) = outer_message.if_typed() {
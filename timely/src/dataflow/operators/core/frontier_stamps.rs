@@ -0,0 +1,102 @@
+//! Report the frontier of a stream as data.
+
+use crate::container::CapacityContainerBuilder;
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::CapabilitySet;
+use crate::dataflow::operators::generic::builder_rc::OperatorBuilder;
+use crate::dataflow::operators::generic::FrontieredInputHandleCore;
+use crate::dataflow::{Scope, StreamCore};
+use crate::progress::Antichain;
+use crate::Container;
+
+/// Report the frontier of a stream as data.
+pub trait FrontierStamps<G: Scope, C: Container> {
+    /// Passes the stream through unchanged, and reports each advance of its frontier on a second stream.
+    ///
+    /// Each time the frontier of the input changes, every element of the new frontier is sent as a
+    /// record on the second stream, at the time of the element itself. The second stream therefore
+    /// holds snapshots of the frontier, interleaved with progress in the same way as the data, which
+    /// can be used to visualize progress without the logging infrastructure.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::core::FrontierStamps;
+    ///
+    /// timely::example(|scope| {
+    ///     let (data, stamps) = (0..10).to_stream(scope).frontier_stamps();
+    ///     data.inspect(|x| println!("data: {:?}", x));
+    ///     stamps.inspect(|t| println!("frontier: {:?}", t));
+    /// });
+    /// ```
+    fn frontier_stamps(&self) -> (StreamCore<G, C>, StreamCore<G, Vec<G::Timestamp>>);
+}
+
+impl<G: Scope, C: Container> FrontierStamps<G, C> for StreamCore<G, C> {
+    fn frontier_stamps(&self) -> (StreamCore<G, C>, StreamCore<G, Vec<G::Timestamp>>) {
+        let mut builder = OperatorBuilder::new("FrontierStamps".to_owned(), self.scope());
+
+        let mut input = builder.new_input(self, Pipeline);
+        let (mut data_output, data_stream) = builder.new_output::<CapacityContainerBuilder<C>>();
+        let (mut stamps_output, stamps_stream) = builder.new_output::<CapacityContainerBuilder<Vec<G::Timestamp>>>();
+
+        builder.build(move |mut capabilities| {
+            // retain only the capability for the stamps output.
+            let mut stamps = CapabilitySet::from_elem(capabilities.pop().unwrap());
+            drop(capabilities);
+
+            let mut previous = Antichain::new();
+            let mut first = true;
+            let mut container = Default::default();
+            move |frontiers| {
+                let mut input_handle = FrontieredInputHandleCore::new_tracked(&mut input, &frontiers[0], &mut previous);
+                let mut data_handle = data_output.activate();
+                input_handle.for_each(|time, data| {
+                    data.swap(&mut container);
+                    data_handle.session(&time).give_container(&mut container);
+                });
+
+                if first || input_handle.frontier_changed() {
+                    first = false;
+                    let frontier = input_handle.frontier().frontier();
+                    let mut stamps_handle = stamps_output.activate();
+                    for time in frontier.iter() {
+                        stamps_handle.session(&stamps.delayed(time)).give(time.clone());
+                    }
+                    stamps.downgrade(frontier.iter());
+                }
+            }
+        });
+
+        (data_stream, stamps_stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::dataflow::operators::{Input, Probe};
+    use crate::dataflow::operators::capture::{Capture, Extract};
+    use super::FrontierStamps;
+
+    #[test]
+    fn stamps_follow_frontier() {
+
+        let stamps = crate::execute_directly(|worker| {
+            let (mut input, probe, stamps) = worker.dataflow::<u64, _, _>(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                let (data, stamps) = stream.frontier_stamps();
+                (input, data.probe(), stamps.capture())
+            });
+            for round in 0 .. 3 {
+                input.send(round);
+                input.advance_to(round + 1);
+                worker.step_while(|| probe.less_than(input.time()));
+            }
+            stamps
+        });
+
+        let stamps = stamps.extract().into_iter().flat_map(|(_, stamps)| stamps).collect::<Vec<_>>();
+        assert_eq!(stamps, vec![0, 1, 2, 3]);
+    }
+}
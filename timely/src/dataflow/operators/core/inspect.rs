@@ -1,5 +1,9 @@
 //! Extension trait and implementation for observing and action on streamed data.
 
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
 use crate::Container;
 use crate::dataflow::channels::pact::Pipeline;
 use crate::dataflow::{Scope, StreamCore};
@@ -49,6 +53,35 @@ pub trait Inspect<G: Scope, C: Container>: InspectCore<G, C> + Sized {
         })
     }
 
+    /// Records the length of each observed data batch into the histogram of `sizes`.
+    ///
+    /// The data are passed through unchanged. The operator holds only a weak reference to
+    /// the histogram, and stops recording once all handles have been dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::inspect::BatchSizes;
+    ///
+    /// timely::execute_directly(|worker| {
+    ///     let sizes = BatchSizes::new();
+    ///     worker.dataflow::<u64,_,_>(|scope| {
+    ///         (0..10).to_stream(scope)
+    ///                .inspect_batch_sizes(&sizes);
+    ///     });
+    ///     while worker.step() { }
+    ///     assert_eq!(sizes.records(), 10);
+    /// });
+    /// ```
+    fn inspect_batch_sizes(&self, sizes: &BatchSizes) -> Self {
+        let histogram = Rc::downgrade(&sizes.histogram);
+        self.inspect_batch(move |_time, data| {
+            if let Some(histogram) = histogram.upgrade() {
+                *histogram.borrow_mut().entry(data.len()).or_insert(0) += 1;
+            }
+        })
+    }
+
     /// Runs a supplied closure on each observed data batch (time and data slice).
     ///
     /// # Examples
@@ -142,3 +175,33 @@ impl<G: Scope, C: Container> InspectCore<G, C> for StreamCore<G, C> {
         })
     }
 }
+
+/// A shared histogram of the lengths of batches observed by `inspect_batch_sizes`.
+#[derive(Clone, Debug, Default)]
+pub struct BatchSizes {
+    histogram: Rc<RefCell<BTreeMap<usize, usize>>>,
+}
+
+impl BatchSizes {
+    /// Allocates a new empty histogram.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Returns pairs of batch length and the number of batches observed with that length,
+    /// in increasing order of length.
+    pub fn histogram(&self) -> Vec<(usize, usize)> {
+        self.histogram.borrow().iter().map(|(length, count)| (*length, *count)).collect()
+    }
+    /// Returns the number of batches observed.
+    pub fn batches(&self) -> usize {
+        self.histogram.borrow().values().sum()
+    }
+    /// Returns the number of records observed, as the sum of all batch lengths.
+    pub fn records(&self) -> usize {
+        self.histogram.borrow().iter().map(|(length, count)| length * count).sum()
+    }
+    /// Discards all observations.
+    pub fn clear(&self) {
+        self.histogram.borrow_mut().clear();
+    }
+}
@@ -1,6 +1,6 @@
 //! Extension methods for `StreamCore` based on record-by-record transformation.
 
-use crate::container::{Container, SizableContainer, PushInto};
+use crate::container::{Container, SizableContainer, PushInto, CapacityContainerBuilder};
 use crate::dataflow::{Scope, StreamCore};
 use crate::dataflow::channels::pact::Pipeline;
 use crate::dataflow::operators::generic::operator::Operator;
@@ -48,6 +48,29 @@ pub trait Map<S: Scope, C: Container> {
         C2: SizableContainer + PushInto<I::Item>,
         L: FnMut(C::Item<'_>)->I + 'static,
     ;
+    /// Consumes each container of the stream and yields a new container.
+    ///
+    /// Unlike `map`, `logic` is applied to whole containers rather than to their elements, and
+    /// its results are sent unmodified at the time of the input container. This avoids moving
+    /// each record when a container can be converted as a whole, for example when re-typing a
+    /// batch or rearranging its contents.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::core::{Map, Inspect};
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .map_container(|batch: Vec<u64>| batch.into_iter().rev().collect::<Vec<_>>())
+    ///            .inspect(|x| println!("seen: {:?}", x));
+    /// });
+    /// ```
+    fn map_container<C2, L>(&self, logic: L) -> StreamCore<S, C2>
+    where
+        C2: Container,
+        L: FnMut(C)->C2 + 'static,
+    ;
 }
 
 impl<S: Scope, C: Container> Map<S, C> for StreamCore<S, C> {
@@ -68,4 +91,16 @@ impl<S: Scope, C: Container> Map<S, C> for StreamCore<S, C> {
             });
         })
     }
+    fn map_container<C2, L>(&self, mut logic: L) -> StreamCore<S, C2>
+    where
+        C2: Container,
+        L: FnMut(C)->C2 + 'static,
+    {
+        self.unary::<CapacityContainerBuilder<C2>, _, _, _>(Pipeline, "MapContainer", move |_,_| move |input, output| {
+            input.for_each(|time, data| {
+                let mut result = logic(data.take());
+                output.session(&time).give_container(&mut result);
+            });
+        })
+    }
 }
@@ -1,33 +1,51 @@
 //! Extension traits for `StreamCore` implementing various operators that
 //! are independent of specific container types.
 
+pub mod ack;
 pub mod capture;
 pub mod concat;
+pub mod consolidate_batches;
+pub mod delay;
 pub mod enterleave;
 pub mod exchange;
 pub mod feedback;
 pub mod filter;
+pub mod frontier_stamps;
 pub mod input;
 pub mod inspect;
 pub mod map;
+pub mod monotonic;
 pub mod ok_err;
+pub mod partition;
 pub mod probe;
+pub mod rates;
 pub mod rc;
+pub mod rebatch;
 pub mod reclock;
+pub mod sample;
 pub mod to_stream;
 pub mod unordered_input;
 
+pub use ack::RetainUntilAcked;
 pub use capture::Capture;
 pub use concat::{Concat, Concatenate};
+pub use consolidate_batches::ConsolidateBatches;
+pub use delay::DelayUntilComplete;
 pub use enterleave::{Enter, Leave};
 pub use exchange::Exchange;
 pub use feedback::{Feedback, LoopVariable, ConnectLoop};
 pub use filter::Filter;
+pub use frontier_stamps::FrontierStamps;
 pub use input::Input;
 pub use inspect::{Inspect, InspectCore};
 pub use map::Map;
+pub use monotonic::AssertMonotonic;
 pub use ok_err::OkErr;
+pub use partition::Partition;
 pub use probe::Probe;
+pub use rates::LogRates;
 pub use to_stream::{ToStream, ToStreamBuilder};
+pub use rebatch::Rebatch;
 pub use reclock::Reclock;
+pub use sample::Sample;
 pub use unordered_input::{UnorderedInput, UnorderedHandle};
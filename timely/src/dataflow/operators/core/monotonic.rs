@@ -0,0 +1,56 @@
+//! Check that the data of a stream respect its frontier.
+
+use crate::container::CapacityContainerBuilder;
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::generic::Operator;
+use crate::dataflow::{Scope, StreamCore};
+use crate::Container;
+
+/// Check that the data of a stream respect its frontier.
+pub trait AssertMonotonic<G: Scope, C: Container> {
+    /// Passes the stream through unchanged, panicking if data arrive at a time the frontier has passed.
+    ///
+    /// Each received container is checked to have a time greater or equal to an element of the
+    /// input frontier. A container at an earlier time indicates that an upstream operator, often
+    /// a custom source, sent data without holding a capability for its time, which corrupts the
+    /// progress information of downstream operators.
+    ///
+    /// This is a debugging aid: when debug assertions are disabled the stream is returned as is,
+    /// and no operator is inserted.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::core::AssertMonotonic;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .assert_monotonic()
+    ///            .inspect(|x| println!("seen: {:?}", x));
+    /// });
+    /// ```
+    fn assert_monotonic(&self) -> StreamCore<G, C>;
+}
+
+impl<G: Scope, C: Container> AssertMonotonic<G, C> for StreamCore<G, C> {
+    fn assert_monotonic(&self) -> StreamCore<G, C> {
+        if !cfg!(debug_assertions) {
+            return self.clone();
+        }
+        let mut container = Default::default();
+        self.unary_frontier::<CapacityContainerBuilder<C>, _, _, _>(Pipeline, "AssertMonotonic", move |_, info| move |input, output| {
+            input.for_each_with_frontier(|time, data, frontier| {
+                if !frontier.less_equal(time.time()) {
+                    panic!(
+                        "AssertMonotonic at {:?}: received data at {:?}, not in advance of the frontier {:?}",
+                        info.address,
+                        time.time(),
+                        frontier.frontier(),
+                    );
+                }
+                data.swap(&mut container);
+                output.session(&time).give_container(&mut container);
+            });
+        })
+    }
+}
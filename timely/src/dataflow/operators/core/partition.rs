@@ -0,0 +1,72 @@
+//! Partition a stream of records into multiple streams.
+
+use crate::container::{Container, SizableContainer, PushInto};
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::generic::builder_rc::OperatorBuilder;
+use crate::dataflow::{Scope, StreamCore};
+
+/// Partition a stream of records into multiple streams.
+pub trait Partition<G: Scope, C: Container> {
+    /// Produces `parts` output streams, containing records produced and assigned by `route`.
+    ///
+    /// The `route` closure returns for each record the index of the output it should be sent
+    /// to, which must be less than `parts`, along with the record to send.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::core::{Partition, Inspect};
+    ///
+    /// timely::example(|scope| {
+    ///     let streams = (0..10).to_stream(scope)
+    ///                          .partition::<Vec<_>, _, _>(3, |x| (x % 3, x));
+    ///
+    ///     streams[0].inspect(|x| println!("seen 0: {:?}", x));
+    ///     streams[1].inspect(|x| println!("seen 1: {:?}", x));
+    ///     streams[2].inspect(|x| println!("seen 2: {:?}", x));
+    /// });
+    /// ```
+    fn partition<C2, D2, F>(&self, parts: u64, route: F) -> Vec<StreamCore<G, C2>>
+    where
+        C2: SizableContainer + PushInto<D2>,
+        F: FnMut(C::Item<'_>) -> (u64, D2) + 'static;
+}
+
+impl<G: Scope, C: Container> Partition<G, C> for StreamCore<G, C> {
+    fn partition<C2, D2, F>(&self, parts: u64, mut route: F) -> Vec<StreamCore<G, C2>>
+    where
+        C2: SizableContainer + PushInto<D2>,
+        F: FnMut(C::Item<'_>) -> (u64, D2) + 'static,
+    {
+        let mut builder = OperatorBuilder::new("Partition".to_owned(), self.scope());
+
+        let mut input = builder.new_input(self, Pipeline);
+        let mut outputs = Vec::with_capacity(parts as usize);
+        let mut streams = Vec::with_capacity(parts as usize);
+
+        for _ in 0 .. parts {
+            let (output, stream) = builder.new_output();
+            outputs.push(output);
+            streams.push(stream);
+        }
+
+        builder.build(move |_| {
+            let mut container = Default::default();
+            move |_frontiers| {
+                let mut handles = outputs.iter_mut().map(|o| o.activate()).collect::<Vec<_>>();
+                input.for_each(|time, data| {
+                    data.swap(&mut container);
+                    let mut sessions = handles.iter_mut().map(|h| h.session(&time)).collect::<Vec<_>>();
+
+                    for datum in container.drain() {
+                        let (part, datum2) = route(datum);
+                        assert!(part < parts, "Partition: record routed to output {}, but only {} outputs exist", part, parts);
+                        sessions[part as usize].give(datum2);
+                    }
+                });
+            }
+        });
+
+        streams
+    }
+}
@@ -1,18 +1,28 @@
 //! Monitor progress at a `Stream`.
 
-use std::rc::Rc;
-use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use std::cell::{Cell, RefCell};
+use std::collections::VecDeque;
+use std::io::Write;
+use std::time::{Duration, Instant};
+use std::sync::mpsc::{Receiver, Sender};
+use std::task::Waker;
 
-use crate::progress::Timestamp;
-use crate::progress::frontier::{AntichainRef, MutableAntichain};
+use crate::communication::Allocate;
+use crate::progress::{ChangeBatch, Timestamp};
+use crate::worker::Worker;
+use crate::logging::{FrontierLogger, FrontierUpdateEvent};
+use crate::progress::frontier::{Antichain, AntichainRef, MutableAntichain};
 use crate::dataflow::channels::pushers::Counter as PushCounter;
 use crate::dataflow::channels::pushers::buffer::Buffer as PushBuffer;
-use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::channels::pact::{ExchangeCore, ParallelizationContract, Pipeline};
 use crate::dataflow::channels::pullers::Counter as PullCounter;
 use crate::dataflow::operators::generic::builder_raw::OperatorBuilder;
-
+use crate::dataflow::operators::capture::{Event, EventPusher};
 
 use crate::dataflow::{StreamCore, Scope};
+use crate::communication::Data;
+use crate::container::PushPartitioned;
 use crate::Container;
 
 /// Monitors progress at a `Stream`.
@@ -77,6 +87,233 @@ pub trait Probe<G: Scope, C: Container> {
     /// }).unwrap();
     /// ```
     fn probe_with(&self, handle: &Handle<G::Timestamp>) -> StreamCore<G, C>;
+
+    /// Inserts a progress probe in a stream, and also captures its progress into `pusher`.
+    ///
+    /// The shared frontier of `handle` is maintained exactly as in `probe_with`, and each change
+    /// to the frontier is additionally reported to `pusher` as an `Event::Progress`, following the
+    /// same protocol as `capture_into`. Data are passed through, and are not sent to `pusher`.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::*;
+    /// use timely::dataflow::Scope;
+    /// use timely::dataflow::operators::{Input, Probe, Inspect};
+    /// use timely::dataflow::operators::probe::Handle;
+    ///
+    /// // construct and execute a timely dataflow
+    /// timely::execute(Config::thread(), |worker| {
+    ///
+    ///     // add an input and base computation off of it
+    ///     let (send, recv) = ::std::sync::mpsc::channel();
+    ///     let mut probe = Handle::new();
+    ///     let mut input = worker.dataflow(|scope| {
+    ///         let (input, stream) = scope.new_input();
+    ///         stream.probe_and_capture_progress(&mut probe, send)
+    ///               .inspect(|x| println!("hello {:?}", x));
+    ///
+    ///         input
+    ///     });
+    ///
+    ///     // introduce input, advance computation
+    ///     for round in 0..10 {
+    ///         input.send(round);
+    ///         input.advance_to(round + 1);
+    ///         worker.step_while(|| probe.less_than(input.time()));
+    ///     }
+    ///
+    ///     // the captured progress describes the frontier advancing.
+    ///     assert!(recv.try_iter().count() > 0);
+    /// }).unwrap();
+    /// ```
+    fn probe_and_capture_progress<P: EventPusher<G::Timestamp, C>+'static>(&self, handle: &Handle<G::Timestamp>, pusher: P) -> StreamCore<G, C>;
+
+    /// Inserts a progress probe in a stream, and invokes `callback` whenever its frontier changes.
+    ///
+    /// The shared frontier of `handle` is maintained exactly as in `probe_with`. The callback is
+    /// invoked with the new frontier only when it has actually changed, rather than on each
+    /// invocation of the operator, which allows a reaction to progress without polling.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::rc::Rc;
+    /// use std::cell::RefCell;
+    /// use timely::*;
+    /// use timely::dataflow::Scope;
+    /// use timely::dataflow::operators::{Input, Probe};
+    /// use timely::dataflow::operators::probe::Handle;
+    ///
+    /// // construct and execute a timely dataflow
+    /// timely::execute(Config::thread(), |worker| {
+    ///
+    ///     // record each frontier the probe reports.
+    ///     let frontiers = Rc::new(RefCell::new(Vec::new()));
+    ///     let frontiers2 = frontiers.clone();
+    ///
+    ///     let mut probe = Handle::new();
+    ///     let mut input = worker.dataflow(|scope| {
+    ///         let (input, stream) = scope.new_input::<u64>();
+    ///         stream.probe_notify(&mut probe, move |frontier| {
+    ///             frontiers2.borrow_mut().push(frontier.to_vec());
+    ///         });
+    ///         input
+    ///     });
+    ///
+    ///     // introduce input, advance computation
+    ///     for round in 0..10 {
+    ///         input.send(round);
+    ///         input.advance_to(round + 1);
+    ///         worker.step_while(|| probe.less_than(input.time()));
+    ///     }
+    ///
+    ///     assert_eq!(frontiers.borrow().last(), Some(&vec![10]));
+    /// }).unwrap();
+    /// ```
+    fn probe_notify<F: FnMut(AntichainRef<G::Timestamp>)+'static>(&self, handle: &Handle<G::Timestamp>, callback: F) -> StreamCore<G, C>;
+
+    /// Inserts a progress probe in a stream, recording the history of its frontier in `handle`.
+    ///
+    /// Each change to the frontier is recorded along with the `Instant` it was observed, which
+    /// allows one to determine when each timestamp became complete.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::*;
+    /// use timely::dataflow::Scope;
+    /// use timely::dataflow::operators::{Input, Probe};
+    /// use timely::dataflow::operators::probe::HistoryHandle;
+    ///
+    /// // construct and execute a timely dataflow
+    /// timely::execute(Config::thread(), |worker| {
+    ///
+    ///     // retain at most the 100 most recent frontiers.
+    ///     let history = HistoryHandle::with_capacity(100);
+    ///     let mut input = worker.dataflow(|scope| {
+    ///         let (input, stream) = scope.new_input::<u64>();
+    ///         stream.probe_with_history(&history);
+    ///         input
+    ///     });
+    ///
+    ///     // introduce input, advance computation
+    ///     for round in 0..10 {
+    ///         input.send(round);
+    ///         input.advance_to(round + 1);
+    ///         worker.step_while(|| history.handle().less_than(input.time()));
+    ///     }
+    ///
+    ///     let (_instant, frontier) = history.history().pop().unwrap();
+    ///     assert_eq!(frontier.elements(), &[10]);
+    /// }).unwrap();
+    /// ```
+    fn probe_with_history(&self, handle: &HistoryHandle<G::Timestamp>) -> StreamCore<G, C>;
+
+    /// Inserts a progress probe in a stream, whose input uses the parallelization contract `pact`.
+    ///
+    /// This behaves as `probe_with`, except that the data are routed to the probe according to
+    /// `pact` rather than remaining on the same worker.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::*;
+    /// use timely::dataflow::Scope;
+    /// use timely::dataflow::channels::pact::Exchange;
+    /// use timely::dataflow::operators::{Input, Probe, Inspect};
+    /// use timely::dataflow::operators::probe::Handle;
+    ///
+    /// // construct and execute a timely dataflow
+    /// timely::execute(Config::thread(), |worker| {
+    ///
+    ///     // add an input and base computation off of it
+    ///     let mut probe = Handle::new();
+    ///     let mut input = worker.dataflow(|scope| {
+    ///         let (input, stream) = scope.new_input();
+    ///         stream.probe_with_pact(Exchange::new(|x: &u64| *x), &mut probe)
+    ///               .inspect(|x| println!("hello {:?}", x));
+    ///
+    ///         input
+    ///     });
+    ///
+    ///     // introduce input, advance computation
+    ///     for round in 0..10 {
+    ///         input.send(round);
+    ///         input.advance_to(round + 1);
+    ///         worker.step_while(|| probe.less_than(input.time()));
+    ///     }
+    /// }).unwrap();
+    /// ```
+    fn probe_with_pact<P: ParallelizationContract<G::Timestamp, C>>(&self, pact: P, handle: &Handle<G::Timestamp>) -> StreamCore<G, C>;
+
+    /// Inserts a progress probe in a stream, whose input is exchanged between workers by `key`.
+    ///
+    /// This is `probe_with_pact` with an `Exchange` contract using `key`, and spares an
+    /// exchange operator ahead of a pipelined probe.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::*;
+    /// use timely::dataflow::Scope;
+    /// use timely::dataflow::operators::{Input, Probe, Inspect};
+    /// use timely::dataflow::operators::probe::Handle;
+    ///
+    /// // construct and execute a timely dataflow
+    /// timely::execute(Config::thread(), |worker| {
+    ///
+    ///     // add an input and base computation off of it
+    ///     let mut probe = Handle::new();
+    ///     let mut input = worker.dataflow(|scope| {
+    ///         let (input, stream) = scope.new_input();
+    ///         stream.probe_exchange(|x: &u64| *x, &mut probe)
+    ///               .inspect(|x| println!("hello {:?}", x));
+    ///
+    ///         input
+    ///     });
+    ///
+    ///     // introduce input, advance computation
+    ///     for round in 0..10 {
+    ///         input.send(round);
+    ///         input.advance_to(round + 1);
+    ///         worker.step_while(|| probe.less_than(input.time()));
+    ///     }
+    /// }).unwrap();
+    /// ```
+    fn probe_exchange<F>(&self, key: F, handle: &Handle<G::Timestamp>) -> StreamCore<G, C>
+    where
+        C: Data + PushPartitioned,
+        for<'a> F: FnMut(&C::Item<'a>)->u64 + 'static;
+
+    /// Maintains the frontier of `handle` at the stream, without continuing the stream.
+    ///
+    /// Unlike `probe_with`, the probe operator has no output: data are received and discarded
+    /// rather than forwarded, which avoids copying each container into an output buffer when
+    /// only the frontier is needed. The stream does not continue past the probe, and so this
+    /// method returns nothing; other operators may still consume the stream.
+    ///
+    /// The probe is nonetheless an operator of the dataflow, which is scheduled and pulls each
+    /// message through a counting puller so that progress tracking learns of its consumption.
+    /// Records it consumes are reported by `Handle::counts`, which for this probe shows nothing
+    /// produced.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{Input, Probe, Inspect};
+    /// use timely::dataflow::operators::probe::Handle;
+    ///
+    /// timely::execute_directly(|worker| {
+    ///     let probe = Handle::new();
+    ///     let mut input = worker.dataflow::<u64, _, _>(|scope| {
+    ///         let (input, stream) = scope.new_input::<u64>();
+    ///         stream.inspect(|x| println!("hello {:?}", x))
+    ///               .probe_frontier_only(&probe);
+    ///         input
+    ///     });
+    ///     for round in 0..10 {
+    ///         input.send(round);
+    ///         input.advance_to(round + 1);
+    ///         worker.step_while(|| probe.less_than(input.time()));
+    ///     }
+    /// });
+    /// ```
+    fn probe_frontier_only(&self, handle: &Handle<G::Timestamp>);
 }
 
 impl<G: Scope, C: Container> Probe<G, C> for StreamCore<G, C> {
@@ -88,60 +325,273 @@ impl<G: Scope, C: Container> Probe<G, C> for StreamCore<G, C> {
         handle
     }
     fn probe_with(&self, handle: &Handle<G::Timestamp>) -> StreamCore<G, C> {
+        probe_operator(self, Pipeline, handle, |_changes| { }, |_frontier| { })
+    }
+    fn probe_and_capture_progress<P: EventPusher<G::Timestamp, C>+'static>(&self, handle: &Handle<G::Timestamp>, mut pusher: P) -> StreamCore<G, C> {
 
-        let mut builder = OperatorBuilder::new("Probe".to_owned(), self.scope());
+        let mut started = false;
+        let mut to_send = ChangeBatch::new();
+
+        probe_operator(self, Pipeline, handle, move |changes| {
+            if !started {
+                // discard initial capability, as `capture_into` does.
+                to_send.update(G::Timestamp::minimum(), -1);
+                started = true;
+            }
+            to_send.extend(changes.iter().cloned());
+            if !to_send.is_empty() {
+                let to_send = ::std::mem::replace(&mut to_send, ChangeBatch::new());
+                pusher.push(Event::Progress(to_send.into_inner()));
+            }
+        }, |_frontier| { })
+    }
+    fn probe_notify<F: FnMut(AntichainRef<G::Timestamp>)+'static>(&self, handle: &Handle<G::Timestamp>, callback: F) -> StreamCore<G, C> {
+        probe_operator(self, Pipeline, handle, |_changes| { }, callback)
+    }
+    fn probe_with_pact<P: ParallelizationContract<G::Timestamp, C>>(&self, pact: P, handle: &Handle<G::Timestamp>) -> StreamCore<G, C> {
+        probe_operator(self, pact, handle, |_changes| { }, |_frontier| { })
+    }
+    fn probe_exchange<F>(&self, key: F, handle: &Handle<G::Timestamp>) -> StreamCore<G, C>
+    where
+        C: Data + PushPartitioned,
+        for<'a> F: FnMut(&C::Item<'a>)->u64 + 'static,
+    {
+        self.probe_with_pact(ExchangeCore::new(key), handle)
+    }
+    fn probe_frontier_only(&self, handle: &Handle<G::Timestamp>) {
+
+        let mut builder = OperatorBuilder::new("ProbeFrontier".to_owned(), self.scope());
+        let frontier_logging = self.scope().log_register().get("timely/frontier");
+        let operator_id = builder.global();
         let mut input = PullCounter::new(builder.new_input(self, Pipeline));
-        let (tee, stream) = builder.new_output();
-        let mut output = PushBuffer::new(PushCounter::new(tee));
 
         let shared_frontier = Rc::downgrade(&handle.frontier);
-        let mut started = false;
+        let shared_records = Rc::downgrade(&handle.records);
+        let shared_version = Rc::downgrade(&handle.version);
+        let shared_started = Rc::downgrade(&handle.started);
+        let shared_subscribers = Rc::downgrade(&handle.subscribers);
+        let shared_wakers = Rc::downgrade(&handle.wakers);
+        let shared_max_seen = Rc::downgrade(&handle.max_seen);
+        let shared_counts = Rc::downgrade(&handle.counts);
 
-        let mut vector = Default::default();
+        let mut changes = Vec::new();
 
         builder.build(
             move |progress| {
 
-                // surface all frontier changes to the shared frontier.
-                if let Some(shared_frontier) = shared_frontier.upgrade() {
-                    let mut borrow = shared_frontier.borrow_mut();
-                    borrow.update_iter(progress.frontiers[0].drain());
-                }
+                changes.extend(progress.frontiers[0].drain());
+                log_frontier_changes(&frontier_logging, operator_id, &changes[..]);
+                update_shared_frontier(&mut changes, &shared_frontier, &shared_version, &shared_subscribers, &shared_wakers);
+                changes.clear();
 
-                if !started {
-                    // discard initial capability.
-                    progress.internals[0].update(G::Timestamp::minimum(), -1);
-                    started = true;
+                if let Some(shared_started) = shared_started.upgrade() {
+                    shared_started.set(true);
                 }
 
-                use crate::communication::message::RefOrMut;
-
+                // discard received data, recording only what the handle reports.
+                let mut consumed = 0;
                 while let Some(message) = input.next() {
-                    let (time, data) = match message.as_ref_or_mut() {
-                        RefOrMut::Ref(reference) => (&reference.time, RefOrMut::Ref(&reference.data)),
-                        RefOrMut::Mut(reference) => (&reference.time, RefOrMut::Mut(&mut reference.data)),
-                    };
-                    data.swap(&mut vector);
-                    output.session(time).give_container(&mut vector);
+                    consumed += message.data.len();
+                    if let Some(shared_records) = shared_records.upgrade() {
+                        shared_records.set(shared_records.get() + message.data.len());
+                    }
+                    if let Some(shared_max_seen) = shared_max_seen.upgrade() {
+                        let mut max_seen = shared_max_seen.borrow_mut();
+                        if max_seen.as_ref().map_or(true, |max| max < &message.time) {
+                            *max_seen = Some(message.time.clone());
+                        }
+                    }
+                }
+                if let Some(shared_counts) = shared_counts.upgrade() {
+                    let (total_consumed, total_produced) = shared_counts.get();
+                    shared_counts.set((total_consumed + consumed, total_produced));
                 }
-                output.cease();
-
-                // extract what we know about progress from the input and output adapters.
                 input.consumed().borrow_mut().drain_into(&mut progress.consumeds[0]);
-                output.inner().produced().borrow_mut().drain_into(&mut progress.produceds[0]);
 
                 false
             },
         );
+    }
+    fn probe_with_history(&self, handle: &HistoryHandle<G::Timestamp>) -> StreamCore<G, C> {
+        let history = Rc::downgrade(&handle.history);
+        let capacity = handle.capacity;
+        probe_operator(self, Pipeline, &handle.handle, |_changes| { }, move |frontier| {
+            if let Some(history) = history.upgrade() {
+                let mut history = history.borrow_mut();
+                while history.len() >= capacity && !history.is_empty() {
+                    history.pop_front();
+                }
+                if capacity > 0 {
+                    history.push_back((Instant::now(), frontier.to_owned()));
+                }
+            }
+        })
+    }
+}
 
-        stream
+/// Constructs a probe operator that maintains the frontier of `handle`.
+///
+/// Each invocation of the operator presents the frontier changes it observed to `observe`,
+/// before they are applied to the shared frontier. The list of changes may be empty.
+/// If applying the changes alters the shared frontier, `notify` is invoked with the result.
+fn probe_operator<G, C, P, L, N>(stream: &StreamCore<G, C>, pact: P, handle: &Handle<G::Timestamp>, mut observe: L, mut notify: N) -> StreamCore<G, C>
+where
+    G: Scope,
+    C: Container,
+    P: ParallelizationContract<G::Timestamp, C>,
+    L: FnMut(&[(G::Timestamp, i64)])+'static,
+    N: FnMut(AntichainRef<G::Timestamp>)+'static,
+{
+    let mut builder = OperatorBuilder::new("Probe".to_owned(), stream.scope());
+    let frontier_logging = stream.scope().log_register().get("timely/frontier");
+    let operator_id = builder.global();
+    let mut input = PullCounter::new(builder.new_input(stream, pact));
+    let (tee, stream) = builder.new_output();
+    let mut output = PushBuffer::new(PushCounter::new(tee));
+
+    let shared_frontier = Rc::downgrade(&handle.frontier);
+    let shared_records = Rc::downgrade(&handle.records);
+    let shared_version = Rc::downgrade(&handle.version);
+    let shared_started = Rc::downgrade(&handle.started);
+    let shared_counts = Rc::downgrade(&handle.counts);
+    let shared_subscribers = Rc::downgrade(&handle.subscribers);
+    let shared_wakers = Rc::downgrade(&handle.wakers);
+    let shared_max_seen = Rc::downgrade(&handle.max_seen);
+    let mut started = false;
+
+    let mut changes = Vec::new();
+    let mut vector = Default::default();
+
+    builder.build(
+        move |progress| {
+
+            // surface all frontier changes to the observer and the shared frontier.
+            changes.extend(progress.frontiers[0].drain());
+            observe(&changes[..]);
+            log_frontier_changes(&frontier_logging, operator_id, &changes[..]);
+            if let Some(frontier) = update_shared_frontier(&mut changes, &shared_frontier, &shared_version, &shared_subscribers, &shared_wakers) {
+                notify(frontier.borrow());
+            }
+            changes.clear();
+
+            if !started {
+                // discard initial capability.
+                progress.internals[0].update(G::Timestamp::minimum(), -1);
+                started = true;
+                if let Some(shared_started) = shared_started.upgrade() {
+                    shared_started.set(true);
+                }
+            }
+
+            use crate::communication::message::RefOrMut;
+
+            while let Some(message) = input.next() {
+                let (time, data) = match message.as_ref_or_mut() {
+                    RefOrMut::Ref(reference) => (&reference.time, RefOrMut::Ref(&reference.data)),
+                    RefOrMut::Mut(reference) => (&reference.time, RefOrMut::Mut(&mut reference.data)),
+                };
+                data.swap(&mut vector);
+                if let Some(shared_records) = shared_records.upgrade() {
+                    shared_records.set(shared_records.get() + vector.len());
+                }
+                if let Some(shared_max_seen) = shared_max_seen.upgrade() {
+                    let mut max_seen = shared_max_seen.borrow_mut();
+                    if max_seen.as_ref().map_or(true, |max| max < time) {
+                        *max_seen = Some(time.clone());
+                    }
+                }
+                output.session(time).give_container(&mut vector);
+            }
+            output.cease();
+
+            // record the numbers of records consumed and produced.
+            if let Some(shared_counts) = shared_counts.upgrade() {
+                let consumed: i64 = input.consumed().borrow_mut().iter().map(|(_, count)| count).sum();
+                let produced: i64 = output.inner().produced().borrow_mut().iter().map(|(_, count)| count).sum();
+                let (total_consumed, total_produced) = shared_counts.get();
+                shared_counts.set((total_consumed + consumed as usize, total_produced + produced as usize));
+            }
+
+            // extract what we know about progress from the input and output adapters.
+            input.consumed().borrow_mut().drain_into(&mut progress.consumeds[0]);
+            output.inner().produced().borrow_mut().drain_into(&mut progress.produceds[0]);
+
+            false
+        },
+    );
+
+    stream
+}
+
+/// Logs the frontier changes observed by a probe operator at its only input, if any.
+fn log_frontier_changes<T: Timestamp>(logging: &Option<FrontierLogger<T>>, operator_id: usize, changes: &[(T, i64)]) {
+    if let Some(logger) = logging.as_ref() {
+        if !changes.is_empty() {
+            logger.log(FrontierUpdateEvent { operator_id, port: 0, changes: changes.to_vec() });
+        }
     }
 }
 
+/// Applies `changes` to the shared frontier of a handle, returning the new frontier if it changed.
+///
+/// A change to the frontier increments the shared version, is sent to each subscriber, and wakes
+/// the tasks awaiting times the frontier has passed.
+fn update_shared_frontier<T: Timestamp>(
+    changes: &mut Vec<(T, i64)>,
+    shared_frontier: &Weak<RefCell<MutableAntichain<T>>>,
+    shared_version: &Weak<Cell<u64>>,
+    shared_subscribers: &Weak<RefCell<Vec<Sender<Antichain<T>>>>>,
+    shared_wakers: &Weak<RefCell<Vec<(T, Waker)>>>,
+) -> Option<Antichain<T>> {
+    let shared_frontier = shared_frontier.upgrade()?;
+    let mut borrow = shared_frontier.borrow_mut();
+    let changed = borrow.update_iter(changes.drain(..)).next().is_some();
+    if !changed {
+        return None;
+    }
+    if let Some(shared_version) = shared_version.upgrade() {
+        shared_version.set(shared_version.get() + 1);
+    }
+    // release the borrow, so that callers may consult the handle.
+    let frontier = borrow.frontier().to_owned();
+    drop(borrow);
+    if let Some(shared_subscribers) = shared_subscribers.upgrade() {
+        // retain only subscribers whose receivers remain.
+        shared_subscribers.borrow_mut().retain(|sender| sender.send(frontier.clone()).is_ok());
+    }
+    if let Some(shared_wakers) = shared_wakers.upgrade() {
+        shared_wakers.borrow_mut().retain(|(time, waker)| {
+            if frontier.less_equal(time) {
+                true
+            }
+            else {
+                waker.wake_by_ref();
+                false
+            }
+        });
+    }
+    Some(frontier)
+}
+
 /// Reports information about progress at the probe.
 #[derive(Debug)]
 pub struct Handle<T:Timestamp> {
-    frontier: Rc<RefCell<MutableAntichain<T>>>
+    frontier: Rc<RefCell<MutableAntichain<T>>>,
+    records: Rc<Cell<usize>>,
+    /// Incremented each time the frontier changes.
+    version: Rc<Cell<u64>>,
+    /// The frontier as a vector, and the version at which it was recorded.
+    cached: Rc<RefCell<Option<(u64, Rc<Vec<T>>)>>>,
+    /// Set once a probe operator using the handle has first run.
+    started: Rc<Cell<bool>>,
+    /// The numbers of records consumed and produced by probe operators using the handle.
+    counts: Rc<Cell<(usize, usize)>>,
+    /// Senders to receivers of frontier changes, as returned by `subscribe`.
+    subscribers: Rc<RefCell<Vec<Sender<Antichain<T>>>>>,
+    /// Wakers of tasks awaiting the completion of times, as registered by `ready`.
+    wakers: Rc<RefCell<Vec<(T, Waker)>>>,
+    /// The greatest timestamp of a message passed through probes using the handle.
+    max_seen: Rc<RefCell<Option<T>>>,
 }
 
 impl<T: Timestamp> Handle<T> {
@@ -149,10 +599,43 @@ impl<T: Timestamp> Handle<T> {
     #[inline] pub fn less_than(&self, time: &T) -> bool { self.frontier.borrow().less_than(time) }
     /// returns true iff the frontier is less than or equal to `time`.
     #[inline] pub fn less_equal(&self, time: &T) -> bool { self.frontier.borrow().less_equal(time) }
-    /// returns true iff the frontier is empty.
-    #[inline] pub fn done(&self) -> bool { self.frontier.borrow().is_empty() }
+    /// returns true iff a probe using the handle has started, and its frontier is empty.
+    ///
+    /// The frontier of a handle is also empty before any probe operator has run, which is
+    /// distinguished from completion by `started`.
+    #[inline] pub fn done(&self) -> bool { self.started() && self.frontier.borrow().is_empty() }
+    /// returns true iff a probe operator using the handle has run at least once.
+    #[inline] pub fn started(&self) -> bool { self.started.get() }
     /// Allocates a new handle.
-    #[inline] pub fn new() -> Self { Handle { frontier: Rc::new(RefCell::new(MutableAntichain::new())) } }
+    #[inline] pub fn new() -> Self {
+        Handle {
+            frontier: Rc::new(RefCell::new(MutableAntichain::new())),
+            records: Rc::new(Cell::new(0)),
+            version: Rc::new(Cell::new(0)),
+            cached: Rc::new(RefCell::new(None)),
+            started: Rc::new(Cell::new(false)),
+            counts: Rc::new(Cell::new((0, 0))),
+            subscribers: Rc::new(RefCell::new(Vec::new())),
+            wakers: Rc::new(RefCell::new(Vec::new())),
+            max_seen: Rc::new(RefCell::new(None)),
+        }
+    }
+    /// Returns the total number of records that have passed through probes using this handle.
+    ///
+    /// Records are counted by `Container::len`, and the count can be sampled over time to
+    /// determine the throughput of the probed streams.
+    #[inline] pub fn record_count(&self) -> usize { self.records.get() }
+    /// Returns the total numbers of records consumed and produced by probes using this handle.
+    ///
+    /// Probes pass their input through unchanged, and so the two counts should be equal once
+    /// the probes have been scheduled, except that `probe_frontier_only` produces nothing.
+    #[inline] pub fn counts(&self) -> (usize, usize) { self.counts.get() }
+    /// Returns the greatest timestamp of a message passed through probes using this handle.
+    ///
+    /// Unlike the frontier, which reports which times are complete, this reports the newest
+    /// time at which data has been seen, which indicates whether data is arriving at all.
+    /// Times are compared with `Ord`, and the result is `None` until a message has been seen.
+    #[inline] pub fn max_seen(&self) -> Option<T> { self.max_seen.borrow().clone() }
 
     /// Invokes a method on the frontier, returning its result.
     ///
@@ -171,12 +654,190 @@ impl<T: Timestamp> Handle<T> {
     pub fn with_frontier<R, F: FnMut(AntichainRef<T>)->R>(&self, mut function: F) -> R {
         function(self.frontier.borrow().frontier())
     }
+
+    /// Returns an owned copy of the current frontier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timely::dataflow::operators::probe::Handle;
+    ///
+    /// let handle = Handle::<usize>::new();
+    /// assert!(handle.frontier().is_empty());
+    /// ```
+    #[inline]
+    pub fn frontier(&self) -> Antichain<T> {
+        self.frontier.borrow().frontier().to_owned()
+    }
+
+    /// Returns the least element of the current frontier, or `None` if the frontier is empty.
+    ///
+    /// For totally ordered timestamps the frontier has at most one element, and this reads it
+    /// without allocating. For partially ordered timestamps the result is the least element
+    /// according to `Ord`, which is at most every element of the frontier in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timely::dataflow::operators::probe::Handle;
+    ///
+    /// let handle = Handle::<usize>::new();
+    /// assert_eq!(handle.frontier_min(), None);
+    /// ```
+    #[inline]
+    pub fn frontier_min(&self) -> Option<T> {
+        self.frontier.borrow().frontier().iter().min().cloned()
+    }
+
+    /// Returns the current frontier as a vector, recomputed only when the frontier has changed.
+    ///
+    /// Repeated calls between frontier changes return the same shared vector, which avoids
+    /// an allocation each time the frontier is polled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timely::dataflow::operators::probe::Handle;
+    ///
+    /// let handle = Handle::<usize>::new();
+    /// let frontier1 = handle.frontier_vec_cached();
+    /// let frontier2 = handle.frontier_vec_cached();
+    /// assert!(std::rc::Rc::ptr_eq(&frontier1, &frontier2));
+    /// ```
+    pub fn frontier_vec_cached(&self) -> Rc<Vec<T>> {
+        let version = self.version.get();
+        let mut cached = self.cached.borrow_mut();
+        match &*cached {
+            Some((cached_version, frontier)) if *cached_version == version => frontier.clone(),
+            _ => {
+                let frontier = Rc::new(self.frontier.borrow().frontier().to_vec());
+                *cached = Some((version, frontier.clone()));
+                frontier
+            }
+        }
+    }
+
+    /// Returns a receiver of the frontier each time it changes.
+    ///
+    /// The probe operator sends the new frontier to each subscriber when it changes, which allows
+    /// another thread, for example a metrics exporter, to observe progress without consulting the
+    /// handle. Frontiers are sent from the next change onwards. Once the receiver is dropped the
+    /// operator stops sending to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use timely::dataflow::operators::{Input, Probe};
+    ///
+    /// timely::execute_directly(|worker| {
+    ///     let (mut input, probe) = worker.dataflow::<u64, _, _>(|scope| {
+    ///         let (input, stream) = scope.new_input::<u64>();
+    ///         (input, stream.probe())
+    ///     });
+    ///     let receiver = probe.subscribe();
+    ///     input.advance_to(1);
+    ///     worker.step_while(|| probe.less_than(input.time()));
+    ///     assert_eq!(receiver.try_iter().last().map(|f| f.elements().to_vec()), Some(vec![1]));
+    /// });
+    /// ```
+    pub fn subscribe(&self) -> Receiver<Antichain<T>> {
+        let (sender, receiver) = ::std::sync::mpsc::channel();
+        self.subscribers.borrow_mut().push(sender);
+        receiver
+    }
+
+    /// Returns a future that completes once the frontier is no longer less or equal to `time`.
+    ///
+    /// The probe operator wakes the awaiting task when its frontier passes `time`, which allows
+    /// async code to await the completion of a time. The future completes only as the worker is
+    /// stepped, and so the worker must be stepped, for example by the task awaiting the future
+    /// or by another task on the same thread, for it to make progress.
+    #[cfg(feature = "async")]
+    pub fn ready(&self, time: T) -> Ready<T> {
+        Ready {
+            time,
+            frontier: self.frontier.clone(),
+            started: self.started.clone(),
+            wakers: self.wakers.clone(),
+        }
+    }
+
+    /// Steps `worker` until the frontier is no longer strictly less than `time`, or `timeout` elapses.
+    ///
+    /// The deadline is checked between steps, and the method returns `true` iff the frontier
+    /// reached `time` before the deadline passed. Until a probe operator using the handle has
+    /// run its frontier is empty, and so the worker is stepped at least until the probe starts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use timely::dataflow::operators::{Input, Probe};
+    ///
+    /// timely::execute_from_args(std::env::args(), |worker| {
+    ///     let (mut input, probe) = worker.dataflow::<u64,_,_>(|scope| {
+    ///         let (input, stream) = scope.new_input::<u64>();
+    ///         (input, stream.probe())
+    ///     });
+    ///
+    ///     input.advance_to(1);
+    ///     assert!(probe.wait_until(&1, worker, Duration::from_secs(10)));
+    /// }).unwrap();
+    /// ```
+    pub fn wait_until<A: Allocate>(&self, time: &T, worker: &mut Worker<A>, timeout: Duration) -> bool {
+        let deadline = Instant::now() + timeout;
+        while !self.started() || self.less_than(time) {
+            if Instant::now() >= deadline {
+                return false;
+            }
+            worker.step();
+        }
+        true
+    }
+}
+
+/// A future that completes once the frontier of a probe passes a time.
+///
+/// Created by `Handle::ready`.
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct Ready<T: Timestamp> {
+    time: T,
+    frontier: Rc<RefCell<MutableAntichain<T>>>,
+    started: Rc<Cell<bool>>,
+    wakers: Rc<RefCell<Vec<(T, Waker)>>>,
+}
+
+#[cfg(feature = "async")]
+impl<T: Timestamp> ::std::future::Future for Ready<T> {
+    type Output = ();
+    fn poll(self: ::std::pin::Pin<&mut Self>, cx: &mut ::std::task::Context<'_>) -> ::std::task::Poll<()> {
+        // the frontier is empty until a probe operator has run, which does not indicate completion.
+        if self.started.get() && !self.frontier.borrow().less_equal(&self.time) {
+            ::std::task::Poll::Ready(())
+        }
+        else {
+            let mut wakers = self.wakers.borrow_mut();
+            if !wakers.iter().any(|(time, waker)| time == &self.time && waker.will_wake(cx.waker())) {
+                wakers.push((self.time.clone(), cx.waker().clone()));
+            }
+            ::std::task::Poll::Pending
+        }
+    }
 }
 
 impl<T: Timestamp> Clone for Handle<T> {
     fn clone(&self) -> Self {
         Handle {
-            frontier: self.frontier.clone()
+            frontier: self.frontier.clone(),
+            records: self.records.clone(),
+            version: self.version.clone(),
+            cached: self.cached.clone(),
+            started: self.started.clone(),
+            counts: self.counts.clone(),
+            subscribers: self.subscribers.clone(),
+            wakers: self.wakers.clone(),
+            max_seen: self.max_seen.clone(),
         }
     }
 }
@@ -190,10 +851,139 @@ where
     }
 }
 
+/// A collection of probe handles, reporting on their combined progress.
+///
+/// Each query is answered for all handles in the group at once, so that one can ask whether
+/// every probed location in a dataflow has reached a time.
+///
+/// # Examples
+///
+/// ```
+/// use timely::dataflow::operators::probe::{Handle, ProbeGroup};
+///
+/// let mut group = ProbeGroup::<usize>::new();
+/// group.add(Handle::new());
+/// group.add(Handle::new());
+/// // neither handle is yet used by a running probe.
+/// assert!(!group.done());
+/// ```
+#[derive(Debug)]
+pub struct ProbeGroup<T: Timestamp> {
+    handles: Vec<Handle<T>>,
+}
+
+impl<T: Timestamp> ProbeGroup<T> {
+    /// Allocates a new empty group.
+    pub fn new() -> Self { ProbeGroup { handles: Vec::new() } }
+    /// Adds a handle to the group.
+    pub fn add(&mut self, handle: Handle<T>) { self.handles.push(handle); }
+    /// returns true iff some handle's frontier is strictly less than `time`.
+    ///
+    /// This is the case exactly when the combined frontier is strictly less than `time`.
+    #[inline] pub fn less_than(&self, time: &T) -> bool { self.handles.iter().any(|h| h.less_than(time)) }
+    /// returns true iff every handle's frontier is less than or equal to `time`.
+    #[inline] pub fn less_equal(&self, time: &T) -> bool { self.handles.iter().all(|h| h.less_equal(time)) }
+    /// returns true iff every handle's frontier is empty.
+    #[inline] pub fn done(&self) -> bool { self.handles.iter().all(|h| h.done()) }
+}
+
+impl<T: Timestamp> Clone for ProbeGroup<T> {
+    fn clone(&self) -> Self {
+        ProbeGroup {
+            handles: self.handles.clone(),
+        }
+    }
+}
+
+impl<T> Default for ProbeGroup<T>
+where
+    T: Timestamp,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reports information about progress at the probe, including a history of its frontier.
+///
+/// The history is a bounded buffer of frontiers and the instants at which they were observed.
+/// Once the buffer reaches its capacity, the oldest entries are evicted to make room.
+#[derive(Debug)]
+pub struct HistoryHandle<T: Timestamp> {
+    handle: Handle<T>,
+    history: Rc<RefCell<VecDeque<(Instant, Antichain<T>)>>>,
+    capacity: usize,
+}
+
+impl<T: Timestamp> HistoryHandle<T> {
+    /// Allocates a new handle retaining at most `capacity` frontiers.
+    pub fn with_capacity(capacity: usize) -> Self {
+        HistoryHandle {
+            handle: Handle::new(),
+            history: Rc::new(RefCell::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+    /// The probe handle reporting the current frontier.
+    pub fn handle(&self) -> &Handle<T> { &self.handle }
+    /// The recorded frontiers, from oldest to most recent, with the instants they were observed.
+    pub fn history(&self) -> Vec<(Instant, Antichain<T>)> {
+        self.history.borrow().iter().cloned().collect()
+    }
+    /// Writes the recorded frontiers to `w` as CSV, from oldest to most recent.
+    ///
+    /// Each row holds the milliseconds elapsed since the oldest recorded frontier, and the
+    /// frontier itself as the `Debug` representations of its elements separated by spaces.
+    /// The frontier is quoted, as timestamps such as tuples may contain commas.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{Input, Probe};
+    /// use timely::dataflow::operators::probe::HistoryHandle;
+    ///
+    /// timely::execute_directly(|worker| {
+    ///     let history = HistoryHandle::with_capacity(16);
+    ///     let mut input = worker.dataflow::<u64, _, _>(|scope| {
+    ///         let (input, stream) = scope.new_input::<u64>();
+    ///         stream.probe_with_history(&history);
+    ///         input
+    ///     });
+    ///     input.advance_to(1);
+    ///     worker.step_while(|| history.handle().less_than(input.time()));
+    ///
+    ///     let mut csv = Vec::new();
+    ///     history.write_csv(&mut csv).unwrap();
+    ///     assert!(String::from_utf8(csv).unwrap().starts_with("elapsed_ms,frontier\n"));
+    /// });
+    /// ```
+    pub fn write_csv<W: Write>(&self, mut w: W) -> ::std::io::Result<()> {
+        let history = self.history.borrow();
+        writeln!(w, "elapsed_ms,frontier")?;
+        let first = history.front().map(|(instant, _)| *instant);
+        for (instant, frontier) in history.iter() {
+            let elapsed = first.map_or(Duration::default(), |first| instant.duration_since(first));
+            let elements = frontier.elements().iter().map(|time| format!("{:?}", time)).collect::<Vec<_>>().join(" ");
+            writeln!(w, "{},\"{}\"", elapsed.as_millis(), elements.replace('"', "\"\""))?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Timestamp> Clone for HistoryHandle<T> {
+    fn clone(&self) -> Self {
+        HistoryHandle {
+            handle: self.handle.clone(),
+            history: self.history.clone(),
+            capacity: self.capacity,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use crate::Config;
+    use std::rc::Rc;
     use crate::dataflow::operators::{Input, Probe};
 
     #[test]
@@ -229,4 +1019,244 @@ mod tests {
         }).unwrap();
     }
 
+    #[test]
+    fn frontier_vec_cached() {
+
+        crate::execute(Config::thread(), |worker| {
+
+            let (mut input, probe) = worker.dataflow(move |scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                (input, stream.probe())
+            });
+
+            for round in 0..10 {
+                input.advance_to(round + 1);
+                worker.step_while(|| probe.less_than(input.time()));
+                let frontier = probe.frontier_vec_cached();
+                assert_eq!(&frontier[..], &[round + 1]);
+                assert!(Rc::ptr_eq(&frontier, &probe.frontier_vec_cached()));
+                assert_eq!(probe.frontier_min(), Some(round + 1));
+            }
+        }).unwrap();
+    }
+
+    #[test]
+    fn max_seen_tracks_messages() {
+
+        crate::execute(Config::thread(), |worker| {
+
+            let (mut input, probe) = worker.dataflow(move |scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                (input, stream.probe())
+            });
+
+            assert_eq!(probe.max_seen(), None);
+            input.advance_to(3);
+            input.send(0);
+            input.advance_to(5);
+            worker.step_while(|| probe.less_than(input.time()));
+            assert_eq!(probe.max_seen(), Some(3));
+        }).unwrap();
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn ready_wakes_on_completion() {
+
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::task::{Context, Poll, Wake};
+
+        struct CountingWaker(AtomicUsize);
+        impl Wake for CountingWaker {
+            fn wake(self: Arc<Self>) { self.0.fetch_add(1, Ordering::SeqCst); }
+        }
+
+        crate::execute(Config::thread(), |worker| {
+
+            let (mut input, probe) = worker.dataflow(move |scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                (input, stream.probe())
+            });
+
+            let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+            let waker = counter.clone().into();
+            let mut context = Context::from_waker(&waker);
+            let mut ready = Box::pin(probe.ready(2));
+
+            worker.step();
+            assert_eq!(ready.as_mut().poll(&mut context), Poll::Pending);
+            input.advance_to(1);
+            worker.step_while(|| probe.less_than(input.time()));
+            assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+            input.advance_to(3);
+            worker.step_while(|| probe.less_than(input.time()));
+            assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+            assert_eq!(ready.as_mut().poll(&mut context), Poll::Ready(()));
+        }).unwrap();
+    }
+
+    #[test]
+    fn done_requires_started() {
+
+        crate::execute(Config::thread(), |worker| {
+
+            let (mut input, probe) = worker.dataflow(move |scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                (input, stream.probe())
+            });
+
+            // the probe operator has not yet run.
+            assert!(!probe.started());
+            assert!(!probe.done());
+
+            input.close();
+            worker.step_while(|| !probe.done());
+            assert!(probe.started());
+        }).unwrap();
+    }
+
+    #[test]
+    fn wait_until_reaches_time() {
+
+        crate::execute(Config::thread(), |worker| {
+
+            let (mut input, probe) = worker.dataflow(move |scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                (input, stream.probe())
+            });
+
+            input.advance_to(3);
+            assert!(probe.wait_until(&3, worker, ::std::time::Duration::from_secs(10)));
+            assert!(probe.started());
+            assert!(!probe.less_than(&3));
+            assert!(probe.less_equal(&3));
+        }).unwrap();
+    }
+
+    #[test]
+    fn wait_until_times_out() {
+
+        crate::execute(Config::thread(), |worker| {
+
+            let (input, probe) = worker.dataflow(move |scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                (input, stream.probe())
+            });
+
+            // the input holds time zero, so time one is never reached.
+            assert!(!probe.wait_until(&1, worker, ::std::time::Duration::from_millis(10)));
+            assert!(probe.less_than(&1));
+            drop(input);
+        }).unwrap();
+    }
+
+    #[test]
+    fn record_count() {
+
+        crate::execute(Config::thread(), |worker| {
+
+            let (mut input, probe) = worker.dataflow(move |scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                (input, stream.probe())
+            });
+
+            for round in 0..10 {
+                input.send(round);
+                input.send(round);
+                input.advance_to(round + 1);
+                worker.step_while(|| probe.less_than(input.time()));
+                assert_eq!(probe.record_count(), 2 * (round as usize + 1));
+                assert_eq!(probe.counts(), (2 * (round as usize + 1), 2 * (round as usize + 1)));
+            }
+        }).unwrap();
+    }
+
+    #[test]
+    fn history_is_bounded() {
+
+        use super::HistoryHandle;
+
+        crate::execute(Config::thread(), |worker| {
+
+            let history = HistoryHandle::with_capacity(3);
+            let mut input = worker.dataflow(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                stream.probe_with_history(&history);
+                input
+            });
+
+            for round in 0..10 {
+                input.advance_to(round + 1);
+                worker.step_while(|| history.handle().less_than(input.time()));
+            }
+
+            let frontiers = history.history().into_iter().map(|(_, f)| f.elements().to_vec()).collect::<Vec<_>>();
+            assert_eq!(frontiers, vec![vec![8], vec![9], vec![10]]);
+        }).unwrap();
+    }
+
+    #[test]
+    fn frontier_updates_logged() {
+
+        use std::sync::{Arc, Mutex};
+        use crate::logging::FrontierUpdateEvent;
+        use crate::progress::frontier::MutableAntichain;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let events2 = Arc::clone(&events);
+        crate::execute_directly(move |worker| {
+            worker.log_register().insert::<FrontierUpdateEvent<u64>, _>("timely/frontier", move |_time, data| {
+                events2.lock().unwrap().extend(data.drain(..).map(|(_, _, event)| event));
+            });
+            let (mut input, probe) = worker.dataflow::<u64, _, _>(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                (input, stream.probe())
+            });
+            for round in 0 .. 3 {
+                input.advance_to(round + 1);
+                worker.step_while(|| probe.less_than(input.time()));
+            }
+        });
+
+        // replaying the changes recovers the frontier, which is empty once the input is dropped.
+        let events = events.lock().unwrap();
+        assert!(!events.is_empty());
+        let mut frontier = MutableAntichain::new();
+        for event in events.iter() {
+            assert_eq!(event.port, 0);
+            frontier.update_iter(event.changes.iter().cloned());
+        }
+        assert!(frontier.frontier().is_empty());
+    }
+
+    #[test]
+    fn history_as_csv() {
+
+        use super::HistoryHandle;
+
+        crate::execute_directly(|worker| {
+
+            let history = HistoryHandle::with_capacity(8);
+            let mut input = worker.dataflow::<u64, _, _>(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                stream.probe_with_history(&history);
+                input
+            });
+
+            for round in 0..3 {
+                input.advance_to(round + 1);
+                worker.step_while(|| history.handle().less_than(input.time()));
+            }
+
+            let mut csv = Vec::new();
+            history.write_csv(&mut csv).unwrap();
+            let csv = String::from_utf8(csv).unwrap();
+            let frontiers = csv.lines().skip(1).map(|line| line.split(',').nth(1).unwrap().to_owned()).collect::<Vec<_>>();
+            assert_eq!(csv.lines().next(), Some("elapsed_ms,frontier"));
+            assert_eq!(frontiers.last().map(|f| f.as_str()), Some("\"3\""));
+            assert!(csv.lines().skip(1).next().unwrap().starts_with("0,"));
+        });
+    }
 }
@@ -0,0 +1,79 @@
+//! Report the rate at which messages traverse a stream to the logging infrastructure.
+
+use std::time::{Duration, Instant};
+
+use crate::container::CapacityContainerBuilder;
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::generic::builder_rc::OperatorBuilder;
+use crate::dataflow::{Scope, StreamCore};
+use crate::logging::MessageRateEvent;
+use crate::scheduling::Scheduler;
+use crate::worker::AsWorker;
+use crate::Container;
+
+/// Report the rate at which messages traverse a stream to the logging infrastructure.
+pub trait LogRates<G: Scope, C: Container> {
+    /// Passes the stream through unchanged, logging the messages it carries.
+    ///
+    /// Every `interval` the operator logs a `MessageRateEvent` to the worker's timely logger,
+    /// reporting the number of messages received on the channel into the operator since the
+    /// previous event. The operator reschedules itself so that windows end even without input,
+    /// until the input is complete. If no timely logger is registered the stream is passed
+    /// through unobserved.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::core::LogRates;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .log_rates(Duration::from_secs(1))
+    ///            .inspect(|x| println!("seen: {:?}", x));
+    /// });
+    /// ```
+    fn log_rates(&self, interval: Duration) -> StreamCore<G, C>;
+}
+
+impl<G: Scope, C: Container> LogRates<G, C> for StreamCore<G, C> {
+    fn log_rates(&self, interval: Duration) -> StreamCore<G, C> {
+        let scope = self.scope();
+        let logging = scope.logging();
+        let mut builder = OperatorBuilder::new("LogRates".to_owned(), scope.clone());
+
+        let mut input = builder.new_input(self, Pipeline);
+        let (mut output, stream) = builder.new_output::<CapacityContainerBuilder<C>>();
+        let channel_id = builder.input_channels()[0];
+        let activator = scope.activator_for(&builder.operator_info().address[..]);
+
+        builder.build(move |_capabilities| {
+            let mut window_start = Instant::now();
+            let mut count = 0;
+            let mut container = Default::default();
+            move |frontiers| {
+                let mut output_handle = output.activate();
+                input.for_each(|time, data| {
+                    data.swap(&mut container);
+                    count += 1;
+                    output_handle.session(&time).give_container(&mut container);
+                });
+
+                if let Some(logger) = logging.as_ref() {
+                    let elapsed = window_start.elapsed();
+                    if elapsed >= interval {
+                        logger.log(MessageRateEvent { channel_id, count, window: elapsed });
+                        window_start = Instant::now();
+                        count = 0;
+                    }
+                    // close the next window even if no input arrives, until the input completes.
+                    if !frontiers[0].frontier().is_empty() {
+                        activator.activate_after(interval.saturating_sub(window_start.elapsed()));
+                    }
+                }
+            }
+        });
+
+        stream
+    }
+}
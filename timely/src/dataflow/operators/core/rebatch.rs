@@ -0,0 +1,97 @@
+//! Regroup the records of a stream into containers of a fixed size.
+
+use crate::container::{CapacityContainerBuilder, PushInto, SizableContainer};
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::InputCapability;
+use crate::dataflow::operators::generic::{Operator, CapabilityStash};
+use crate::dataflow::{Scope, StreamCore};
+
+/// Regroup the records of a stream into containers of a fixed size.
+pub trait Rebatch<G: Scope, C: SizableContainer> {
+    /// Regroups the records of the stream into containers of exactly `n` records.
+    ///
+    /// Records are buffered for each time, with a capability for the time, and sent as soon as `n`
+    /// have accumulated. Once the input frontier passes a time, its remaining records are sent
+    /// as one smaller container and the capability is released. The order of records at each
+    /// time is preserved. This normalizes batch sizes for operators sensitive to container size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::core::Rebatch;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .rebatch(4)
+    ///            .inspect_batch(|_t, xs: &[u64]| assert!(xs.len() <= 4));
+    /// });
+    /// ```
+    fn rebatch(&self, n: usize) -> StreamCore<G, C>;
+}
+
+impl<G: Scope, C> Rebatch<G, C> for StreamCore<G, C>
+where
+    C: SizableContainer + for<'a> PushInto<C::ItemRef<'a>>,
+{
+    fn rebatch(&self, n: usize) -> StreamCore<G, C> {
+        assert!(n > 0, "rebatch requires a positive batch size");
+        let mut pending = CapabilityStash::<G::Timestamp, C>::new();
+        let mut container = Default::default();
+        self.unary_frontier::<CapacityContainerBuilder<C>, _, _, _>(Pipeline, "Rebatch", move |_, _| move |input, output| {
+            input.for_each(|cap, data| {
+                data.swap(&mut container);
+                let (held, batch) = pending.entry(cap, InputCapability::retain);
+                let mut session = output.session(held);
+                for item in container.iter() {
+                    batch.push_into(item);
+                    if batch.len() == n {
+                        session.give_container(batch);
+                    }
+                }
+                container.clear();
+            });
+
+            // send the remaining records of completed times, releasing their capabilities.
+            pending.for_each(&[input.frontier()], |held, mut batch| {
+                output.session(&held).give_container(&mut batch);
+            });
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::dataflow::operators::{Input, Probe};
+    use crate::dataflow::operators::capture::{Capture, Event};
+    use super::Rebatch;
+
+    #[test]
+    fn batches_of_fixed_size() {
+
+        let captured = crate::execute_directly(|worker| {
+            let (mut input, probe, captured) = worker.dataflow::<u64, _, _>(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                let rebatched = stream.rebatch(3);
+                (input, rebatched.probe(), rebatched.capture())
+            });
+            input.send_batch(&mut vec![0, 1, 2, 3, 4]);
+            input.send_batch(&mut vec![5, 6, 7]);
+            input.advance_to(1);
+            input.send_batch(&mut vec![8]);
+            input.advance_to(2);
+            worker.step_while(|| probe.less_than(input.time()));
+            captured
+        });
+
+        let batches = captured.try_iter().filter_map(|event| match event {
+            Event::Messages(time, data) => Some((time, data)),
+            Event::Progress(_) => None,
+        }).collect::<Vec<_>>();
+        assert_eq!(batches, vec![(0, vec![0, 1, 2]), (0, vec![3, 4, 5]), (0, vec![6, 7]), (1, vec![8])]);
+    }
+}
@@ -0,0 +1,104 @@
+//! Select a sample of the records of a stream.
+
+use crate::container::{CapacityContainerBuilder, PushInto, SizableContainer};
+use crate::dataflow::channels::pact::Pipeline;
+use crate::dataflow::operators::generic::builder_rc::OperatorBuilder;
+use crate::dataflow::{Scope, StreamCore};
+use crate::worker::AsWorker;
+
+/// Select a sample of the records of a stream.
+pub trait Sample<G: Scope, C: SizableContainer> {
+    /// Passes the stream through unchanged, and sends a sample of its records to a second stream.
+    ///
+    /// Each record is selected with probability `fraction`, using a pseudo-random generator seeded
+    /// by the index of the worker, so that a dataflow selects the same sample each time it runs with
+    /// the same input. Records are sent to the sample stream at their original times, which allows
+    /// a small fraction of a high-volume stream to be inspected without observing all of it.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::core::Sample;
+    ///
+    /// timely::example(|scope| {
+    ///     let (stream, sample) = (0..100).to_stream(scope).sample(0.01);
+    ///     stream.inspect(|x| assert!(*x < 100));
+    ///     sample.inspect(|x| println!("sampled: {:?}", x));
+    /// });
+    /// ```
+    fn sample(&self, fraction: f64) -> (StreamCore<G, C>, StreamCore<G, C>);
+}
+
+impl<G: Scope, C> Sample<G, C> for StreamCore<G, C>
+where
+    C: SizableContainer + for<'a> PushInto<C::ItemRef<'a>>,
+{
+    fn sample(&self, fraction: f64) -> (StreamCore<G, C>, StreamCore<G, C>) {
+        let mut builder = OperatorBuilder::new("Sample".to_owned(), self.scope());
+
+        let mut input = builder.new_input(self, Pipeline);
+        let (mut data_output, data_stream) = builder.new_output::<CapacityContainerBuilder<C>>();
+        let (mut sample_output, sample_stream) = builder.new_output::<CapacityContainerBuilder<C>>();
+
+        let mut state = 0x9E37_79B9_7F4A_7C15u64 ^ self.scope().index() as u64;
+        // records are selected when a uniform value in `[0, 2^64)` falls below `threshold`.
+        let threshold = (fraction.clamp(0.0, 1.0) * u64::MAX as f64) as u64;
+
+        builder.build(move |_capabilities| {
+            let mut container = Default::default();
+            move |_frontiers| {
+                let mut data_handle = data_output.activate();
+                let mut sample_handle = sample_output.activate();
+                input.for_each(|time, data| {
+                    data.swap(&mut container);
+                    let mut sample_session = sample_handle.session(&time);
+                    for item in container.iter() {
+                        if splitmix64(&mut state) < threshold {
+                            sample_session.give(item);
+                        }
+                    }
+                    data_handle.session(&time).give_container(&mut container);
+                });
+            }
+        });
+
+        (data_stream, sample_stream)
+    }
+}
+
+/// Advances `state` and returns the next value of the SplitMix64 generator.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::dataflow::operators::ToStream;
+    use crate::dataflow::operators::capture::{Capture, Extract};
+    use super::Sample;
+
+    #[test]
+    fn sample_is_deterministic() {
+
+        let run = || {
+            let (all, sample) = crate::example(|scope| {
+                let (stream, sample) = (0 .. 1000u64).to_stream(scope).sample(0.1);
+                (stream.capture(), sample.capture())
+            });
+            let all = all.extract().into_iter().flat_map(|(_, data)| data).collect::<Vec<_>>();
+            let sample = sample.extract().into_iter().flat_map(|(_, data)| data).collect::<Vec<_>>();
+            (all, sample)
+        };
+
+        let (all, sample) = run();
+        assert_eq!(all.len(), 1000);
+        assert!(!sample.is_empty() && sample.len() < 200);
+        assert!(sample.iter().all(|x| *x < 1000));
+        assert_eq!(run().1, sample);
+    }
+}
@@ -61,6 +61,7 @@ pub struct OperatorBuilder<G: Scope> {
     address: Vec<usize>,    // path to the operator (ending with index).
     shape: OperatorShape,
     summary: Vec<Vec<Antichain<<G::Timestamp as Timestamp>::Summary>>>,
+    channels: Vec<usize>,   // identifiers of the channels connected to each input.
 }
 
 impl<G: Scope> OperatorBuilder<G> {
@@ -81,6 +82,7 @@ impl<G: Scope> OperatorBuilder<G> {
             address,
             shape: OperatorShape::new(name, peers),
             summary: vec![],
+            channels: vec![],
         }
     }
 
@@ -99,6 +101,11 @@ impl<G: Scope> OperatorBuilder<G> {
         &self.shape
     }
 
+    /// The worker-unique identifiers of the channels connected to the operator's inputs, in order.
+    pub fn input_channels(&self) -> &[usize] {
+        &self.channels[..]
+    }
+
     /// Indicates whether the operator requires frontier information.
     pub fn set_notify(&mut self, notify: bool) {
         self.shape.notify = notify;
@@ -123,6 +130,7 @@ impl<G: Scope> OperatorBuilder<G> {
         let target = Target::new(self.index, self.shape.inputs);
         stream.connect_to(target, sender, channel_id);
 
+        self.channels.push(channel_id);
         self.shape.inputs += 1;
         assert_eq!(self.shape.outputs, connection.len());
         self.summary.push(connection);
@@ -16,12 +16,13 @@ use crate::dataflow::channels::pushers::Counter as PushCounter;
 use crate::dataflow::channels::pushers::buffer::Buffer as PushBuffer;
 use crate::dataflow::channels::pact::ParallelizationContract;
 use crate::dataflow::channels::pullers::Counter as PullCounter;
-use crate::dataflow::operators::capability::Capability;
+use crate::dataflow::operators::capability::{Capability, CapabilityLogger};
 use crate::dataflow::operators::generic::handles::{InputHandleCore, new_input_handle, OutputWrapper};
 use crate::dataflow::operators::generic::operator_info::OperatorInfo;
 use crate::dataflow::operators::generic::builder_raw::OperatorShape;
 
 use crate::logging::TimelyLogger as Logger;
+use crate::logging::{FrontierLogger, FrontierUpdateEvent};
 
 use super::builder_raw::OperatorBuilder as OperatorBuilderRaw;
 
@@ -36,6 +37,18 @@ pub struct OperatorBuilder<G: Scope> {
     summaries: Vec<Rc<RefCell<Vec<Antichain<<G::Timestamp as Timestamp>::Summary>>>>>,
     produced: Vec<Rc<RefCell<ChangeBatch<G::Timestamp>>>>,
     logging: Option<Logger>,
+    frontier_logging: Option<FrontierLogger<G::Timestamp>>,
+    capability_logging: Option<CapabilityLogger>,
+    teardown: Option<Teardown>,
+}
+
+/// Logic to run once an operator is complete, registered with `OperatorBuilder::on_teardown`.
+struct Teardown(Box<dyn FnOnce()>);
+
+impl std::fmt::Debug for Teardown {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("Teardown")
+    }
 }
 
 impl<G: Scope> OperatorBuilder<G> {
@@ -43,17 +56,51 @@ impl<G: Scope> OperatorBuilder<G> {
     /// Allocates a new generic operator builder from its containing scope.
     pub fn new(name: String, scope: G) -> Self {
         let logging = scope.logging();
+        let frontier_logging = scope.log_register().get("timely/frontier");
+        let capability_logger = scope.log_register().get("timely/capabilities");
+        let builder = OperatorBuilderRaw::new(name, scope);
+        let capability_logging = capability_logger.map(|logger| CapabilityLogger::new(logger, builder.global()));
         OperatorBuilder {
-            builder: OperatorBuilderRaw::new(name, scope),
+            builder,
             frontier: Vec::new(),
             consumed: Vec::new(),
             internal: Rc::new(RefCell::new(Vec::new())),
             summaries: Vec::new(),
             produced: Vec::new(),
             logging,
+            frontier_logging,
+            capability_logging,
+            teardown: None,
         }
     }
 
+    /// Registers `teardown` to run once the operator is complete, replacing any earlier registration.
+    ///
+    /// The operator is complete once the frontiers of all its inputs are empty and it holds no
+    /// capabilities, at which point it can neither receive nor produce data. The closure runs once,
+    /// at the end of the invocation that produced the operator's final progress updates, which
+    /// makes it a deterministic place to flush and close external resources, unlike `Drop`.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::builder_rc::OperatorBuilder;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     let stream = (0..10).to_stream(scope);
+    ///     let mut builder = OperatorBuilder::new("Sink".to_owned(), scope.clone());
+    ///     let mut input = builder.new_input(&stream, Pipeline);
+    ///     builder.on_teardown(|| println!("sink complete"));
+    ///     builder.build(|_capabilities| move |_frontiers| {
+    ///         input.for_each(|_time, data| println!("seen: {:?}", &data[..]));
+    ///     });
+    /// });
+    /// ```
+    pub fn on_teardown<F: FnOnce()+'static>(&mut self, teardown: F) {
+        self.teardown = Some(Teardown(Box::new(teardown)));
+    }
+
     /// Indicates whether the operator requires frontier information.
     pub fn set_notify(&mut self, notify: bool) {
         self.builder.set_notify(notify);
@@ -86,10 +133,11 @@ impl<G: Scope> OperatorBuilder<G> {
         self.frontier.push(MutableAntichain::new());
         self.consumed.push(input.consumed().clone());
 
+        let port = self.summaries.len();
         let shared_summary = Rc::new(RefCell::new(connection));
         self.summaries.push(shared_summary.clone());
 
-        new_input_handle(input, self.internal.clone(), shared_summary, self.logging.clone())
+        new_input_handle(input, self.internal.clone(), shared_summary, self.logging.clone(), self.capability_logging.clone(), self.builder.global(), port)
     }
 
     /// Adds a new output to a generic operator builder, returning the `Push` implementor to use.
@@ -113,13 +161,34 @@ impl<G: Scope> OperatorBuilder<G> {
         OutputWrapper<G::Timestamp, CB, Tee<G::Timestamp, CB::Container>>,
         StreamCore<G, CB::Container>
     ) {
+        self.new_output_connection_with_builder(connection, Default::default())
+    }
+
+    /// Adds a new output to a generic operator builder, whose containers are formed by `builder`.
+    ///
+    /// This allows a configured builder, for example `CapacityContainerBuilder::with_capacity`,
+    /// to control when the output sends containers.
+    pub fn new_output_with_builder<CB: ContainerBuilder>(&mut self, builder: CB) -> (OutputWrapper<G::Timestamp, CB, Tee<G::Timestamp, CB::Container>>, StreamCore<G, CB::Container>) {
+        let connection = vec![Antichain::from_elem(Default::default()); self.builder.shape().inputs()];
+        self.new_output_connection_with_builder(connection, builder)
+    }
+
+    /// Adds a new output with connection information, whose containers are formed by `builder`.
+    pub fn new_output_connection_with_builder<CB: ContainerBuilder>(
+        &mut self,
+        connection: Vec<Antichain<<G::Timestamp as Timestamp>::Summary>>,
+        builder: CB,
+    ) -> (
+        OutputWrapper<G::Timestamp, CB, Tee<G::Timestamp, CB::Container>>,
+        StreamCore<G, CB::Container>
+    ) {
 
         let (tee, stream) = self.builder.new_output_connection(connection.clone());
 
         let internal = Rc::new(RefCell::new(ChangeBatch::new()));
         self.internal.borrow_mut().push(internal.clone());
 
-        let mut buffer = PushBuffer::new(PushCounter::new(tee));
+        let mut buffer = PushBuffer::with_builder(PushCounter::new(tee), builder);
         self.produced.push(buffer.inner().produced().clone());
 
         for (summary, connection) in self.summaries.iter().zip(connection.into_iter()) {
@@ -155,7 +224,7 @@ impl<G: Scope> OperatorBuilder<G> {
         // create capabilities, discard references to their creation.
         let mut capabilities = Vec::with_capacity(self.internal.borrow().len());
         for batch in self.internal.borrow().iter() {
-            capabilities.push(Capability::new(G::Timestamp::minimum(), batch.clone()));
+            capabilities.push(Capability::new_logged(G::Timestamp::minimum(), batch.clone(), self.capability_logging.clone()));
             // Discard evidence of creation, as we are assumed to start with one.
             batch.borrow_mut().clear();
         }
@@ -166,12 +235,23 @@ impl<G: Scope> OperatorBuilder<G> {
         let self_consumed = self.consumed;
         let self_internal = self.internal;
         let self_produced = self.produced;
+        let frontier_logging = self.frontier_logging;
+        let mut teardown = self.teardown;
+        // the number of capabilities held, starting from one for each output.
+        let mut held = self_internal.borrow().len() as i64;
+        let operator_id = self.builder.global();
 
         let raw_logic =
         move |progress: &mut SharedProgress<G::Timestamp>| {
 
             // drain frontier changes
-            for (progress, frontier) in progress.frontiers.iter_mut().zip(self_frontier.iter_mut()) {
+            for (port, (progress, frontier)) in progress.frontiers.iter_mut().zip(self_frontier.iter_mut()).enumerate() {
+                if let Some(logger) = frontier_logging.as_ref() {
+                    if !progress.is_empty() {
+                        let changes = progress.iter().cloned().collect();
+                        logger.log(FrontierUpdateEvent { operator_id, port, changes });
+                    }
+                }
                 frontier.update_iter(progress.drain());
             }
 
@@ -187,6 +267,9 @@ impl<G: Scope> OperatorBuilder<G> {
             let self_internal_borrow = self_internal.borrow_mut();
             for index in 0 .. self_internal_borrow.len() {
                 let mut borrow = self_internal_borrow[index].borrow_mut();
+                if teardown.is_some() {
+                    held += borrow.iter().map(|(_, diff)| diff).sum::<i64>();
+                }
                 progress.internals[index].extend(borrow.drain());
             }
 
@@ -195,6 +278,10 @@ impl<G: Scope> OperatorBuilder<G> {
                 produced.borrow_mut().drain_into(progress);
             }
 
+            if teardown.is_some() && held == 0 && self_frontier.iter().all(|frontier| frontier.frontier().is_empty()) {
+                (teardown.take().unwrap().0)();
+            }
+
             result
         };
 
@@ -216,6 +303,11 @@ impl<G: Scope> OperatorBuilder<G> {
         self.builder.shape()
     }
 
+    /// The worker-unique identifiers of the channels connected to the operator's inputs, in order.
+    pub fn input_channels(&self) -> &[usize] {
+        self.builder.input_channels()
+    }
+
     /// Creates operator info for the operator.
     pub fn operator_info(&self) -> OperatorInfo {
         self.builder.operator_info()
@@ -295,4 +387,79 @@ mod tests {
             "Hello".to_owned()
         });
     }
+
+    #[test]
+    fn input_capability_ports() {
+
+        // This tests that input capabilities report the input through which they arrived.
+
+        use std::rc::Rc;
+        use std::cell::RefCell;
+        use crate::dataflow::channels::pact::Pipeline;
+        use crate::dataflow::operators::ToStream;
+        use crate::dataflow::operators::generic::builder_rc::OperatorBuilder;
+
+        crate::execute_directly(|worker| {
+
+            let ports = Rc::new(RefCell::new(Vec::new()));
+            let ports2 = ports.clone();
+
+            worker.dataflow::<u64, _, _>(move |scope| {
+
+                let stream1 = (0 .. 1u64).to_stream(scope);
+                let stream2 = (0 .. 1u64).to_stream(scope);
+
+                let mut builder = OperatorBuilder::new("Ports".to_owned(), scope.clone());
+                let mut input1 = builder.new_input(&stream1, Pipeline);
+                let mut input2 = builder.new_input(&stream2, Pipeline);
+
+                builder.build(move |_capabilities| {
+                    move |_frontiers| {
+                        input1.for_each(|cap, _data| ports2.borrow_mut().push(cap.port()));
+                        input2.for_each(|cap, _data| ports2.borrow_mut().push(cap.port()));
+                    }
+                });
+            });
+
+            while worker.step() { }
+            assert_eq!(*ports.borrow(), vec![0, 1]);
+        });
+    }
+
+    #[test]
+    fn teardown_runs_once_complete() {
+
+        use std::cell::Cell;
+        use std::rc::Rc;
+
+        use crate::dataflow::channels::pact::Pipeline;
+        use crate::dataflow::operators::generic::builder_rc::OperatorBuilder;
+        use crate::dataflow::operators::Input;
+
+        crate::execute_directly(|worker| {
+
+            let torn_down = Rc::new(Cell::new(0));
+            let torn_down2 = Rc::clone(&torn_down);
+            let mut input = worker.dataflow::<u64, _, _>(|scope| {
+                let (input, stream) = scope.new_input::<u64>();
+                let mut builder = OperatorBuilder::new("Teardown".to_owned(), scope.clone());
+                let mut input_handle = builder.new_input(&stream, Pipeline);
+                builder.on_teardown(move || torn_down2.set(torn_down2.get() + 1));
+                builder.build(|_capabilities| move |_frontiers| {
+                    input_handle.for_each(|_time, _data| { });
+                });
+                input
+            });
+
+            for round in 0 .. 3 {
+                input.send(round);
+                input.advance_to(round + 1);
+                for _ in 0 .. 4 { worker.step(); }
+                assert_eq!(torn_down.get(), 0);
+            }
+            input.close();
+            while worker.step() { }
+            assert_eq!(torn_down.get(), 1);
+        });
+    }
 }
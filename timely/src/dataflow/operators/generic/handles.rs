@@ -12,15 +12,15 @@ use crate::progress::ChangeBatch;
 use crate::progress::frontier::MutableAntichain;
 use crate::dataflow::channels::pullers::Counter as PullCounter;
 use crate::dataflow::channels::pushers::Counter as PushCounter;
-use crate::dataflow::channels::pushers::buffer::{Buffer, Session};
+use crate::dataflow::channels::pushers::buffer::{Buffer, Session, MaxLenSession};
 use crate::dataflow::channels::Bundle;
 use crate::communication::{Push, Pull, message::RefOrMut};
 use crate::Container;
-use crate::container::{ContainerBuilder, CapacityContainerBuilder};
+use crate::container::{ContainerBuilder, CapacityContainerBuilder, SizableContainer, PushInto};
 use crate::logging::TimelyLogger as Logger;
 
 use crate::dataflow::operators::InputCapability;
-use crate::dataflow::operators::capability::CapabilityTrait;
+use crate::dataflow::operators::capability::{CapabilityTrait, CapabilityLogger};
 
 /// Handle to an operator's input stream.
 pub struct InputHandleCore<T: Timestamp, C: Container, P: Pull<Bundle<T, C>>> {
@@ -32,6 +32,12 @@ pub struct InputHandleCore<T: Timestamp, C: Container, P: Pull<Bundle<T, C>>> {
     /// greater or equal to the input timestamp subjected to at least one of these summaries.
     summaries: Rc<RefCell<Vec<Antichain<T::Summary>>>>, 
     logging: Option<Logger>,
+    /// Worker-unique identifier of the operator, reported in logged events.
+    operator: usize,
+    /// Reports the capabilities minted from this input, if logging is enabled.
+    capability_logging: Option<CapabilityLogger>,
+    /// The index of this input among the operator's inputs.
+    port: usize,
 }
 
 /// Handle to an operator's input stream, specialized to vectors.
@@ -43,6 +49,8 @@ pub struct FrontieredInputHandleCore<'a, T: Timestamp, C: Container+'a, P: Pull<
     pub handle: &'a mut InputHandleCore<T, C, P>,
     /// The frontier as reported by timely progress tracking.
     pub frontier: &'a MutableAntichain<T>,
+    /// Whether the frontier may have changed since the previous invocation.
+    changed: bool,
 }
 
 /// Handle to an operator's input stream and frontier, specialized to vectors.
@@ -57,18 +65,153 @@ impl<'a, T: Timestamp, C: Container, P: Pull<Bundle<T, C>>> InputHandleCore<T, C
     pub fn next(&mut self) -> Option<(InputCapability<T>, RefOrMut<C>)> {
         let internal = &self.internal;
         let summaries = &self.summaries;
+        let logging = &self.capability_logging;
+        let port = self.port;
         self.pull_counter.next_guarded().map(|(guard, bundle)| {
             match bundle.as_ref_or_mut() {
                 RefOrMut::Ref(bundle) => {
-                    (InputCapability::new(internal.clone(), summaries.clone(), guard), RefOrMut::Ref(&bundle.data))
+                    (InputCapability::new(internal.clone(), summaries.clone(), guard, logging.clone(), port), RefOrMut::Ref(&bundle.data))
                 },
                 RefOrMut::Mut(bundle) => {
-                    (InputCapability::new(internal.clone(), summaries.clone(), guard), RefOrMut::Mut(&mut bundle.data))
+                    (InputCapability::new(internal.clone(), summaries.clone(), guard, logging.clone(), port), RefOrMut::Mut(&mut bundle.data))
                 },
             }
         })
     }
 
+    /// Reads the next input buffer (at some timestamp `t`), with a corresponding capability for `t`
+    /// and the tag of its message, as set by `OutputHandleCore::session_tagged`.
+    ///
+    /// Messages not sent from a tagged session have tag `None`.
+    /// Returns `None` when there's no more data available.
+    #[inline]
+    pub fn next_tagged(&mut self) -> Option<(InputCapability<T>, Option<u32>, RefOrMut<C>)> {
+        let internal = &self.internal;
+        let summaries = &self.summaries;
+        let logging = &self.capability_logging;
+        let port = self.port;
+        self.pull_counter.next_guarded().map(|(guard, bundle)| {
+            match bundle.as_ref_or_mut() {
+                RefOrMut::Ref(bundle) => {
+                    (InputCapability::new(internal.clone(), summaries.clone(), guard, logging.clone(), port), bundle.tag, RefOrMut::Ref(&bundle.data))
+                },
+                RefOrMut::Mut(bundle) => {
+                    (InputCapability::new(internal.clone(), summaries.clone(), guard, logging.clone(), port), bundle.tag, RefOrMut::Mut(&mut bundle.data))
+                },
+            }
+        })
+    }
+
+    /// Reads the next input buffer as an owned container, with a corresponding capability.
+    ///
+    /// The container is swapped out of the message and replaced by an empty container, or cloned
+    /// if the message is shared. This allows operators to retain batches without copying them.
+    /// Returns `None` when there's no more data available.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary(Pipeline, "example", |_cap, _info| {
+    ///                let mut stash = Vec::new();
+    ///                move |input, output| {
+    ///                    while let Some((cap, data)) = input.next_owned() {
+    ///                        stash.push((cap, data));
+    ///                    }
+    ///                    for (cap, mut data) in stash.drain(..) {
+    ///                        output.session(&cap).give_container(&mut data);
+    ///                    }
+    ///                }
+    ///            });
+    /// });
+    /// ```
+    #[inline]
+    pub fn next_owned(&mut self) -> Option<(InputCapability<T>, C)> {
+        self.next().map(|(cap, data)| (cap, data.take()))
+    }
+
+    /// Reads the next input buffer, merged with the buffers immediately following it at the same time.
+    ///
+    /// Only buffers already available are merged, and a buffer at another time ends the merge, so
+    /// the same time may be returned again later. The records of later buffers are moved into the
+    /// first, which reduces the number of calls an operator makes for finely fragmented input.
+    /// Returns `None` when there's no more data available.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Concatenate};
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     let streams = vec![(0..5).to_stream(scope), (5..10).to_stream(scope)];
+    ///     scope.concatenate(streams)
+    ///          .unary(Pipeline, "example", |_cap, _info| |input, output| {
+    ///              for (cap, mut data) in input.merged() {
+    ///                  output.session(&cap).give_container(&mut data);
+    ///              }
+    ///          });
+    /// });
+    /// ```
+    pub fn next_merged(&mut self) -> Option<(InputCapability<T>, C)> where for<'b> C: PushInto<C::Item<'b>> {
+        let (cap, mut container) = self.next_owned()?;
+        while self.peek_time() == Some(cap.time()) {
+            let (_cap, mut data) = self.next_owned().unwrap();
+            for item in data.drain() {
+                container.push(item);
+            }
+        }
+        Some((cap, container))
+    }
+
+    /// An iterator over the available input, merging consecutive buffers at the same time.
+    ///
+    /// Each item is the result of `next_merged`.
+    pub fn merged(&mut self) -> Merged<'_, T, C, P> where for<'b> C: PushInto<C::Item<'b>> {
+        Merged { handle: self }
+    }
+
+    /// Reveals the timestamp of the next input buffer, without consuming it.
+    ///
+    /// The buffer remains pending, and will be returned by the next call to `next`.
+    /// Returns `None` when there's no more data available.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary(Pipeline, "example", |_cap, _info| |input, output| {
+    ///                while let Some(time) = input.peek_time().cloned() {
+    ///                    let (cap, data) = input.next().unwrap();
+    ///                    assert_eq!(cap.time(), &time);
+    ///                    output.session(&cap).give_container(&mut data.replace(Vec::new()));
+    ///                }
+    ///            });
+    /// });
+    /// ```
+    #[inline]
+    pub fn peek_time(&mut self) -> Option<&T> {
+        self.pull_counter.peek().map(|bundle| &bundle.time)
+    }
+
+    /// A lower bound on the number of input buffers available to `next`.
+    ///
+    /// Pending buffers cannot be counted without pulling them, and so this reports only
+    /// a buffer revealed by `peek_time`. A non-zero result guarantees that `next` will
+    /// return data, but a zero result does not indicate the input is empty.
+    #[inline]
+    pub fn pending_len(&self) -> usize {
+        self.pull_counter.pending_len()
+    }
+
     /// Repeatedly calls `logic` till exhaustion of the available input data.
     /// `logic` receives a capability and an input buffer.
     ///
@@ -90,10 +233,56 @@ impl<'a, T: Timestamp, C: Container, P: Pull<Bundle<T, C>>> InputHandleCore<T, C
     #[inline]
     pub fn for_each<F: FnMut(InputCapability<T>, RefOrMut<C>)>(&mut self, mut logic: F) {
         let mut logging = self.logging.take();
+        let operator = self.operator;
         while let Some((cap, data)) = self.next() {
-            logging.as_mut().map(|l| l.log(crate::logging::GuardedMessageEvent { is_start: true }));
+            logging.as_mut().map(|l| l.log(crate::logging::GuardedMessageEvent { operator, is_start: true }));
             logic(cap, data);
-            logging.as_mut().map(|l| l.log(crate::logging::GuardedMessageEvent { is_start: false }));
+            logging.as_mut().map(|l| l.log(crate::logging::GuardedMessageEvent { operator, is_start: false }));
+        }
+        self.logging = logging;
+    }
+
+    /// Repeatedly calls `logic` for each time present in the available input data.
+    /// `logic` receives a capability and the input buffers at its time.
+    ///
+    /// All available buffers are read before `logic` is first called, and grouped by time in the
+    /// order times are first encountered. This does not wait for the frontier to pass a time: only
+    /// buffers already received are grouped, and the same time may be presented again by a later
+    /// call once more buffers arrive. Buffers at a time are presented in the order they were read.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Concatenate};
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     let streams = vec![(0..5).to_stream(scope), (5..10).to_stream(scope)];
+    ///     scope.concatenate(streams)
+    ///          .unary(Pipeline, "example", |_cap, _info| |input, output| {
+    ///              input.for_each_time(|cap, data| {
+    ///                  let mut session = output.session(&cap);
+    ///                  for container in data {
+    ///                      session.give_container(container);
+    ///                  }
+    ///              });
+    ///          });
+    /// });
+    /// ```
+    pub fn for_each_time<F: FnMut(InputCapability<T>, Vec<&mut C>)>(&mut self, mut logic: F) {
+        let mut stash: Vec<(InputCapability<T>, Vec<C>)> = Vec::new();
+        while let Some((cap, data)) = self.next_owned() {
+            match stash.iter_mut().find(|(stashed, _)| stashed.time() == cap.time()) {
+                Some((_, containers)) => containers.push(data),
+                None => stash.push((cap, vec![data])),
+            }
+        }
+        let mut logging = self.logging.take();
+        let operator = self.operator;
+        for (cap, mut containers) in stash {
+            logging.as_mut().map(|l| l.log(crate::logging::GuardedMessageEvent { operator, is_start: true }));
+            logic(cap, containers.iter_mut().collect());
+            logging.as_mut().map(|l| l.log(crate::logging::GuardedMessageEvent { operator, is_start: false }));
         }
         self.logging = logging;
     }
@@ -106,6 +295,23 @@ impl<'a, T: Timestamp, C: Container, P: Pull<Bundle<T, C>>+'a> FrontieredInputHa
         FrontieredInputHandleCore {
             handle,
             frontier,
+            changed: true,
+        }
+    }
+
+    /// Allocate a new frontiered input handle, which reports whether `frontier` differs from `previous`.
+    ///
+    /// The contents of `previous` are replaced with the current frontier, so that the same antichain
+    /// can be supplied to each invocation of an operator.
+    pub fn new_tracked(handle: &'a mut InputHandleCore<T, C, P>, frontier: &'a MutableAntichain<T>, previous: &mut Antichain<T>) -> Self {
+        let changed = previous.borrow() != frontier.frontier();
+        if changed {
+            *previous = frontier.frontier().to_owned();
+        }
+        FrontieredInputHandleCore {
+            handle,
+            frontier,
+            changed,
         }
     }
 
@@ -117,6 +323,12 @@ impl<'a, T: Timestamp, C: Container, P: Pull<Bundle<T, C>>+'a> FrontieredInputHa
         self.handle.next()
     }
 
+    /// Reads the next input buffer, with a corresponding capability and the tag of its message.
+    #[inline]
+    pub fn next_tagged(&mut self) -> Option<(InputCapability<T>, Option<u32>, RefOrMut<C>)> {
+        self.handle.next_tagged()
+    }
+
     /// Repeatedly calls `logic` till exhaustion of the available input data.
     /// `logic` receives a capability and an input buffer.
     ///
@@ -140,11 +352,55 @@ impl<'a, T: Timestamp, C: Container, P: Pull<Bundle<T, C>>+'a> FrontieredInputHa
         self.handle.for_each(logic)
     }
 
+    /// Repeatedly calls `logic` for each time present in the available input data.
+    /// `logic` receives a capability and the input buffers at its time.
+    ///
+    /// See `InputHandleCore::for_each_time`, which this calls.
+    #[inline]
+    pub fn for_each_time<F: FnMut(InputCapability<T>, Vec<&mut C>)>(&mut self, logic: F) {
+        self.handle.for_each_time(logic)
+    }
+
+    /// Repeatedly calls `logic` till exhaustion of the available input data.
+    /// `logic` receives a capability, an input buffer, and the frontier of this input.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary_frontier(Pipeline, "example", |_cap,_info| |input, output| {
+    ///                input.for_each_with_frontier(|cap, data, frontier| {
+    ///                    if !frontier.less_than(cap.time()) {
+    ///                        output.session(&cap).give_container(&mut data.replace(Vec::new()));
+    ///                    }
+    ///                });
+    ///            });
+    /// });
+    /// ```
+    #[inline]
+    pub fn for_each_with_frontier<F: FnMut(InputCapability<T>, RefOrMut<C>, &MutableAntichain<T>)>(&mut self, mut logic: F) {
+        let frontier = self.frontier;
+        self.handle.for_each(|cap, data| logic(cap, data, frontier))
+    }
+
     /// Inspect the frontier associated with this input.
     #[inline]
     pub fn frontier(&self) -> &'a MutableAntichain<T> {
         self.frontier
     }
+
+    /// Indicates whether the frontier changed since the previous invocation of the operator.
+    ///
+    /// Operators can use this to skip frontier-driven logic when nothing has advanced. If the
+    /// handle was not constructed with `new_tracked` this conservatively returns `true`.
+    #[inline]
+    pub fn frontier_changed(&self) -> bool {
+        self.changed
+    }
 }
 
 pub fn _access_pull_counter<T: Timestamp, C: Container, P: Pull<Bundle<T, C>>>(input: &mut InputHandleCore<T, C, P>) -> &mut PullCounter<T, C, P> {
@@ -153,17 +409,27 @@ pub fn _access_pull_counter<T: Timestamp, C: Container, P: Pull<Bundle<T, C>>>(i
 
 /// Constructs an input handle.
 /// Declared separately so that it can be kept private when `InputHandle` is re-exported.
+///
+/// The `operator` identifier is reported in the events the handle logs, and `port` is the
+/// index of the input among the operator's inputs. Capabilities minted from the input report
+/// their actions to `capability_logging`, if present.
 pub fn new_input_handle<T: Timestamp, C: Container, P: Pull<Bundle<T, C>>>(
     pull_counter: PullCounter<T, C, P>, 
     internal: Rc<RefCell<Vec<Rc<RefCell<ChangeBatch<T>>>>>>, 
     summaries: Rc<RefCell<Vec<Antichain<T::Summary>>>>, 
-    logging: Option<Logger>
+    logging: Option<Logger>,
+    capability_logging: Option<CapabilityLogger>,
+    operator: usize,
+    port: usize,
 ) -> InputHandleCore<T, C, P> {
     InputHandleCore {
         pull_counter,
         internal,
         summaries,
         logging,
+        operator,
+        capability_logging,
+        port,
     }
 }
 
@@ -178,7 +444,56 @@ pub struct OutputWrapper<T: Timestamp, CB: ContainerBuilder, P: Push<Bundle<T, C
     internal_buffer: Rc<RefCell<ChangeBatch<T>>>,
 }
 
+impl<T: Timestamp, C: Container, P: Push<Bundle<T, C>>> OutputWrapper<T, CapacityContainerBuilder<C>, P> {
+    /// The number of records buffered but not yet sent.
+    ///
+    /// This reads the length of the buffer, and does not cause it to be flushed.
+    pub fn pending_len(&self) -> usize {
+        self.push_buffer.builder().pending_len()
+    }
+
+    /// Changes the number of records at which this output ships containers.
+    ///
+    /// This allows an operator to change its batching without being rebuilt, for example using
+    /// small containers for low latency while warming up, and large containers once in a steady
+    /// state. Supplying `None` restores the container's preferred capacity.
+    pub fn set_capacity(&mut self, capacity: Option<usize>) where C: SizableContainer {
+        self.push_buffer.set_capacity(capacity);
+    }
+}
+
 impl<T: Timestamp, CB: ContainerBuilder, P: Push<Bundle<T, CB::Container>>> OutputWrapper<T, CB, P> {
+    /// Allocates an empty container builder with the configuration of this output's builder.
+    ///
+    /// The builder can be supplied to `new_output_with_builder` when constructing other operators,
+    /// for example those nested in a composite operator, so that their outputs batch data as this
+    /// output does.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::generic::builder_rc::OperatorBuilder;
+    /// use timely::container::CapacityContainerBuilder;
+    ///
+    /// timely::example(|scope| {
+    ///     let mut builder = OperatorBuilder::new("Parent".to_owned(), scope.clone());
+    ///     let (output, _stream) = builder.new_output_with_builder(CapacityContainerBuilder::<Vec<u64>>::with_capacity(16));
+    ///     let config = output.builder_config();
+    ///     assert_eq!(config.capacity(), Some(16));
+    ///     builder.build(|_capabilities| |_frontiers| { });
+    /// });
+    /// ```
+    pub fn builder_config(&self) -> CB {
+        self.push_buffer.builder().clone_empty()
+    }
+
+    /// Sets a hook to be invoked with each container as it is sent from this output.
+    ///
+    /// The hook fires both for containers sent because the builder completed them, and for
+    /// those sent when the output ceases.
+    pub fn set_on_flush<F: FnMut(&CB::Container)+'static>(&mut self, hook: F) {
+        self.push_buffer.set_on_flush(hook);
+    }
+
     /// Creates a new output wrapper from a push buffer.
     pub fn new(push_buffer: Buffer<T, CB, PushCounter<T, CB::Container, P>>, internal_buffer: Rc<RefCell<ChangeBatch<T>>>) -> Self {
         OutputWrapper {
@@ -236,10 +551,235 @@ impl<'a, T: Timestamp, CB: ContainerBuilder, P: Push<Bundle<T, CB::Container>>>
         self.push_buffer.session_with_builder(cap.time())
     }
 
+    /// Obtains a session that can send data at the timestamp associated with capability `cap`, in messages tagged with `tag`.
+    ///
+    /// The tag is carried by each message the session produces, and is available to downstream
+    /// operators through `InputHandleCore::next_tagged`, without being part of the data. Tags
+    /// survive pipeline and exchange channels, streams with multiple consumers, and entering or
+    /// leaving scopes. Data buffered with another time or tag is sent first.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    /// use timely::container::CapacityContainerBuilder;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10u64).to_stream(scope)
+    ///            .unary::<CapacityContainerBuilder<_>, _, _, _>(Pipeline, "Tag", |_cap, _info| |input, output| {
+    ///                input.for_each(|cap, data| {
+    ///                    for datum in data.take() {
+    ///                        output.session_tagged(&cap, (datum % 2) as u32).give(datum);
+    ///                    }
+    ///                });
+    ///            })
+    ///            .sink(Pipeline, "Check", |input| {
+    ///                while let Some((_cap, tag, data)) = input.next_tagged() {
+    ///                    assert!(data.iter().all(|x: &u64| Some((x % 2) as u32) == tag));
+    ///                }
+    ///            });
+    /// });
+    /// ```
+    pub fn session_tagged<'b, CT: CapabilityTrait<T>>(&'b mut self, cap: &'b CT, tag: u32) -> Session<'b, T, CB, PushCounter<T, CB::Container, P>> where 'a: 'b {
+        assert!(cap.valid_for_output(&self.internal_buffer), "Attempted to open output session with invalid capability");
+        self.push_buffer.session_tagged(cap.time(), tag)
+    }
+
+    /// Obtains a session that sends containers of at most `max_len` records, at the timestamp associated with capability `cap`.
+    ///
+    /// The session flushes the output each time it has been given `max_len` records, which
+    /// bounds the number of records in each message regardless of the container builder.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    /// use timely::container::CapacityContainerBuilder;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary::<CapacityContainerBuilder<_>, _, _, _>(Pipeline, "example", |_cap, _info| |input, output| {
+    ///                input.for_each(|cap, data| {
+    ///                    output.max_len_session(&cap, 3).give_iterator(data.take().into_iter());
+    ///                });
+    ///            })
+    ///            .inspect_batch(|_t, xs: &[u64]| assert!(xs.len() <= 3));
+    /// });
+    /// ```
+    pub fn max_len_session<'b, CT: CapabilityTrait<T>>(&'b mut self, cap: &'b CT, max_len: usize) -> MaxLenSession<'b, T, CB, PushCounter<T, CB::Container, P>> where 'a: 'b {
+        assert!(cap.valid_for_output(&self.internal_buffer), "Attempted to open output session with invalid capability");
+        self.push_buffer.max_len_session(cap.time(), max_len)
+    }
+
+    /// Obtains a session that can send data at the timestamp associated with capability `cap`,
+    /// or `None` if `cap` is not valid for this output.
+    ///
+    /// Unlike `session_with_builder`, this method does not panic on an invalid capability.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    /// use timely::container::CapacityContainerBuilder;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary::<CapacityContainerBuilder<_>, _, _, _>(Pipeline, "example", |_cap, _info| |input, output| {
+    ///                input.for_each(|cap, data| {
+    ///                    if let Some(mut session) = output.try_session(&cap) {
+    ///                        session.give_container(&mut data.replace(Vec::new()));
+    ///                    }
+    ///                });
+    ///            });
+    /// });
+    /// ```
+    pub fn try_session<'b, CT: CapabilityTrait<T>>(&'b mut self, cap: &'b CT) -> Option<Session<'b, T, CB, PushCounter<T, CB::Container, P>>> where 'a: 'b {
+        if cap.valid_for_output(&self.internal_buffer) {
+            Some(self.push_buffer.session_with_builder(cap.time()))
+        }
+        else {
+            None
+        }
+    }
+
+    /// Gives each element of `iter` at the timestamp associated with capability `cap`.
+    ///
+    /// The elements are given through a single session, and the container builder determines
+    /// when containers are sent. Any remaining data are sent when the handle ceases.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    /// use timely::container::CapacityContainerBuilder;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary::<CapacityContainerBuilder<_>, _, _, _>(Pipeline, "example", |_cap, _info| |input, output| {
+    ///                input.for_each(|cap, data| {
+    ///                    output.give_iterator(&cap, data.iter().map(|x| x + 1));
+    ///                });
+    ///            });
+    /// });
+    /// ```
+    pub fn give_iterator<CT: CapabilityTrait<T>, I>(&mut self, cap: &CT, iter: I)
+    where
+        I: IntoIterator,
+        CB: PushInto<I::Item>,
+    {
+        self.session_with_builder(cap).give_iterator(iter.into_iter());
+    }
+
+    /// Starts counting the records sent through this output at each time.
+    ///
+    /// Counting is opt-in, as it adds work for each container sent. The setting persists across
+    /// invocations of the operator, and the counts accumulate until `take_record_counts`.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary(Pipeline, "example", |_cap, _info| |input, output| {
+    ///                output.count_records_by_time();
+    ///                input.for_each(|cap, data| {
+    ///                    output.session(&cap).give_container(&mut data.replace(Vec::new()));
+    ///                });
+    ///                output.flush();
+    ///                for (time, count) in output.take_record_counts() {
+    ///                    println!("sent {} records at {:?}", count, time);
+    ///                }
+    ///            });
+    /// });
+    /// ```
+    pub fn count_records_by_time(&mut self) {
+        self.push_buffer.count_records();
+    }
+
+    /// Removes and returns the numbers of records sent through this output at each time.
+    ///
+    /// Records are counted once sent, so records still buffered are not included; call `flush`
+    /// first to include them. Returns an empty map unless `count_records_by_time` was called.
+    pub fn take_record_counts(&mut self) -> ::std::collections::HashMap<T, usize> {
+        self.push_buffer.take_record_counts().into_iter().collect()
+    }
+
+    /// Obtains a helper that accepts records at multiple capabilities at once.
+    ///
+    /// Records given to the helper are staged by time, and sent when the helper is flushed or
+    /// dropped. The records for each time are sent together, with times sent in the order
+    /// in which they were first given records, and records at the same time sent in the order
+    /// they were given. The interleaving of writes is therefore deterministic.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    /// use timely::container::CapacityContainerBuilder;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary::<CapacityContainerBuilder<_>, _, _, _>(Pipeline, "example", |_cap, _info| |input, output| {
+    ///                let mut sessions = output.sessions();
+    ///                input.for_each(|cap, data| {
+    ///                    let later = cap.delayed(&(cap.time() + 1));
+    ///                    for datum in data.iter() {
+    ///                        sessions.give(&cap, *datum);
+    ///                        sessions.give(&later, *datum);
+    ///                    }
+    ///                });
+    ///            });
+    /// });
+    /// ```
+    pub fn sessions<'b, D>(&'b mut self) -> Sessions<'b, 'a, T, CB, P, D> where CB: PushInto<D> {
+        Sessions {
+            handle: self,
+            staged: Vec::new(),
+        }
+    }
+
     /// Flushes all pending data and indicate that no more data immediately follows.
     pub fn cease(&mut self) {
         self.push_buffer.cease();
     }
+
+    /// Sends all buffered data downstream, without indicating that no more data follows.
+    ///
+    /// Where `cease` also signals the end of a batch, allowing downstream pushers (for example
+    /// those that exchange data between workers) to ship their own buffers, `flush` only empties
+    /// the buffer of this output. Data may be sent through the output afterwards as before,
+    /// which makes `flush` suitable for bounding the latency of records produced ahead of a long
+    /// computation.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    /// use timely::dataflow::operators::generic::Operator;
+    /// use timely::dataflow::channels::pact::Pipeline;
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .unary(Pipeline, "example", |_cap, _info| |input, output| {
+    ///                input.for_each(|cap, data| {
+    ///                    output.session(&cap).give_container(&mut data.replace(Vec::new()));
+    ///                    output.flush();
+    ///                    // ... expensive work ...
+    ///                });
+    ///            });
+    /// });
+    /// ```
+    #[inline]
+    pub fn flush(&mut self) {
+        self.push_buffer.flush();
+    }
 }
 
 impl<'a, T: Timestamp, C: Container, P: Push<Bundle<T, C>>> OutputHandleCore<'a, T, CapacityContainerBuilder<C>, P> {
@@ -269,6 +809,74 @@ impl<'a, T: Timestamp, C: Container, P: Push<Bundle<T, C>>> OutputHandleCore<'a,
     pub fn session<'b, CT: CapabilityTrait<T>>(&'b mut self, cap: &'b CT) -> Session<'b, T, CapacityContainerBuilder<C>, PushCounter<T, C, P>> where 'a: 'b {
         self.session_with_builder(cap)
     }
+
+    /// The number of records buffered but not yet sent.
+    ///
+    /// This reads the length of the buffer, and does not cause it to be flushed.
+    #[inline]
+    pub fn pending_len(&self) -> usize {
+        self.push_buffer.builder().pending_len()
+    }
+
+    /// Changes the number of records at which this output ships containers.
+    ///
+    /// See `OutputWrapper::set_capacity`.
+    #[inline]
+    pub fn set_capacity(&mut self, capacity: Option<usize>) where C: SizableContainer {
+        self.push_buffer.set_capacity(capacity);
+    }
+}
+
+/// An iterator over input buffers, merging consecutive buffers at the same time.
+///
+/// Created by `InputHandleCore::merged`.
+pub struct Merged<'b, T: Timestamp, C: Container, P: Pull<Bundle<T, C>>> {
+    handle: &'b mut InputHandleCore<T, C, P>,
+}
+
+impl<'b, T: Timestamp, C: Container, P: Pull<Bundle<T, C>>> Iterator for Merged<'b, T, C, P>
+where
+    for<'c> C: PushInto<C::Item<'c>>,
+{
+    type Item = (InputCapability<T>, C);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.handle.next_merged()
+    }
+}
+
+/// Stages records sent at multiple capabilities to the same output.
+///
+/// Created by `OutputHandleCore::sessions`, and flushes its staged records when dropped.
+pub struct Sessions<'b, 'a: 'b, T: Timestamp, CB: ContainerBuilder+PushInto<D>+'a, P: Push<Bundle<T, CB::Container>>+'a, D> {
+    handle: &'b mut OutputHandleCore<'a, T, CB, P>,
+    staged: Vec<(T, Vec<D>)>,
+}
+
+impl<'b, 'a: 'b, T: Timestamp, CB: ContainerBuilder+PushInto<D>, P: Push<Bundle<T, CB::Container>>, D> Sessions<'b, 'a, T, CB, P, D> {
+    /// Stages one record at the timestamp associated with capability `cap`.
+    pub fn give<CT: CapabilityTrait<T>>(&mut self, cap: &CT, data: D) {
+        assert!(cap.valid_for_output(self.handle.internal_buffer), "Attempted to give data with invalid capability");
+        let time = cap.time();
+        if let Some((_, records)) = self.staged.iter_mut().find(|(t, _)| t == time) {
+            records.push(data);
+        }
+        else {
+            self.staged.push((time.clone(), vec![data]));
+        }
+    }
+
+    /// Sends all staged records, grouped by time in the order times were first staged.
+    pub fn flush(&mut self) {
+        for (time, records) in self.staged.drain(..) {
+            self.handle.push_buffer.session_with_builder(&time).give_iterator(records.into_iter());
+        }
+    }
+}
+
+impl<'b, 'a: 'b, T: Timestamp, CB: ContainerBuilder+PushInto<D>, P: Push<Bundle<T, CB::Container>>, D> Drop for Sessions<'b, 'a, T, CB, P, D> {
+    fn drop(&mut self) {
+        self.flush();
+    }
 }
 
 impl<'a, T: Timestamp, CB: ContainerBuilder, P: Push<Bundle<T, CB::Container>>> Drop for OutputHandleCore<'a, T, CB, P> {
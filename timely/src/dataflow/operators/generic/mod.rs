@@ -9,7 +9,8 @@ mod notificator;
 mod operator_info;
 
 pub use self::handles::{InputHandle, InputHandleCore, FrontieredInputHandle, FrontieredInputHandleCore, OutputHandle, OutputHandleCore, OutputWrapper};
-pub use self::notificator::{Notificator, FrontierNotificator};
+pub use self::notificator::{Notificator, FrontierNotificator, StashingNotificator};
+pub(crate) use self::notificator::CapabilityStash;
 
 pub use self::operator::{Operator, source};
 pub use self::operator_info::OperatorInfo;
@@ -1,6 +1,7 @@
 use crate::progress::frontier::{AntichainRef, MutableAntichain};
 use crate::progress::Timestamp;
 use crate::dataflow::operators::Capability;
+use crate::dataflow::operators::capability::CapabilityTrait;
 use crate::logging::TimelyLogger as Logger;
 
 /// Tracks requests for notification and delivers available notifications.
@@ -422,6 +423,132 @@ impl<T: Timestamp> FrontierNotificator<T> {
     }
 }
 
+/// Stashes data with capabilities, and delivers both once their time is complete.
+///
+/// A `StashingNotificator` serves operators that buffer their input until a time completes.
+/// Data registered with `notify_with` are retained along with a capability for their time,
+/// and `for_each` presents each capability and its data once no frontier element is less
+/// or equal to the time. Completed times are delivered in increasing order.
+///
+/// # Examples
+/// ```
+/// use timely::dataflow::operators::{ToStream, Inspect};
+/// use timely::dataflow::operators::generic::operator::Operator;
+/// use timely::dataflow::operators::generic::StashingNotificator;
+/// use timely::dataflow::channels::pact::Pipeline;
+///
+/// timely::example(|scope| {
+///     (0..10).to_stream(scope)
+///            .unary_frontier(Pipeline, "example", |_, _| {
+///                let mut stash = StashingNotificator::new();
+///                move |input, output| {
+///                    input.for_each(|cap, data| {
+///                        stash.notify_with(cap.retain(), data.iter().cloned());
+///                    });
+///                    stash.for_each(&[input.frontier()], |cap, data| {
+///                        output.session(&cap).give_iterator(data.into_iter());
+///                    });
+///                }
+///            })
+///            .inspect(|x| println!("seen: {:?}", x));
+/// });
+/// ```
+#[derive(Debug)]
+pub struct StashingNotificator<T: Timestamp, D> {
+    stash: CapabilityStash<T, Vec<D>>,
+}
+
+impl<T: Timestamp, D> StashingNotificator<T, D> {
+    /// Allocates a new `StashingNotificator`.
+    pub fn new() -> Self {
+        StashingNotificator {
+            stash: CapabilityStash::new(),
+        }
+    }
+
+    /// Retains `data` until the time of `cap` is complete.
+    ///
+    /// Data registered at the same time are delivered together, with one capability.
+    pub fn notify_with<I: IntoIterator<Item=D>>(&mut self, cap: Capability<T>, data: I) {
+        self.stash.entry(cap, |cap| cap).1.extend(data);
+    }
+
+    /// Presents each completed time, in increasing order, with its capability and stashed data.
+    ///
+    /// A time is complete once no element of any of `frontiers` is less or equal to it.
+    pub fn for_each<'a, F: FnMut(Capability<T>, Vec<D>)>(&mut self, frontiers: &'a [&'a MutableAntichain<T>], logic: F) {
+        self.stash.for_each(frontiers, logic);
+    }
+
+    /// Returns true iff no data are stashed.
+    pub fn is_empty(&self) -> bool {
+        self.stash.is_empty()
+    }
+}
+
+impl<T: Timestamp, D> Default for StashingNotificator<T, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Per-time state held with a capability for its time, until the time is complete.
+///
+/// Operators that accumulate state for each time, and act on it once the time completes, use
+/// `entry` to find or create the state for a received time and `for_each` to retire completed
+/// times. At most one capability is held for each time.
+#[derive(Debug)]
+pub(crate) struct CapabilityStash<T: Timestamp, S> {
+    stash: Vec<(Capability<T>, S)>,
+}
+
+impl<T: Timestamp, S> CapabilityStash<T, S> {
+    /// Allocates a new empty `CapabilityStash`.
+    pub(crate) fn new() -> Self {
+        CapabilityStash { stash: Vec::new() }
+    }
+
+    /// Returns the capability and state for the time of `cap`, creating them if absent.
+    ///
+    /// A capability is obtained from `cap` with `retain` only when the time is not yet stashed.
+    pub(crate) fn entry<C, R>(&mut self, cap: C, retain: R) -> &mut (Capability<T>, S)
+    where
+        C: CapabilityTrait<T>,
+        R: FnOnce(C) -> Capability<T>,
+        S: Default,
+    {
+        let position = match self.stash.iter().position(|(held, _)| held.time() == cap.time()) {
+            Some(position) => position,
+            None => {
+                self.stash.push((retain(cap), S::default()));
+                self.stash.len() - 1
+            },
+        };
+        &mut self.stash[position]
+    }
+
+    /// Presents each completed time, in increasing order, with its capability and state.
+    ///
+    /// A time is complete once no element of any of `frontiers` is less or equal to it.
+    pub(crate) fn for_each<'a, F: FnMut(Capability<T>, S)>(&mut self, frontiers: &'a [&'a MutableAntichain<T>], mut logic: F) {
+        let complete = |cap: &Capability<T>| frontiers.iter().all(|f| !f.less_equal(cap.time()));
+        if !self.stash.iter().any(|(cap, _)| complete(cap)) {
+            return;
+        }
+        let (mut ready, pending): (Vec<_>, Vec<_>) = self.stash.drain(..).partition(|(cap, _)| complete(cap));
+        self.stash = pending;
+        ready.sort_by(|x, y| x.0.time().cmp(y.0.time()));
+        for (cap, state) in ready {
+            logic(cap, state);
+        }
+    }
+
+    /// Returns true iff no times are stashed.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.stash.is_empty()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 struct OrderReversed<T: Timestamp> {
     element: Capability<T>,
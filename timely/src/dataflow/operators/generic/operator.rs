@@ -8,6 +8,7 @@ use crate::dataflow::operators::generic::handles::{InputHandleCore, FrontieredIn
 use crate::dataflow::operators::capability::Capability;
 
 use crate::dataflow::{Scope, StreamCore};
+use crate::progress::frontier::Antichain;
 
 use super::builder_rc::OperatorBuilder;
 use crate::dataflow::operators::generic::OperatorInfo;
@@ -349,8 +350,9 @@ impl<G: Scope, C1: Container> Operator<G, C1> for StreamCore<G, C1> {
             // `capabilities` should be a single-element vector.
             let capability = capabilities.pop().unwrap();
             let mut logic = constructor(capability, operator_info);
+            let mut previous = Antichain::new();
             move |frontiers| {
-                let mut input_handle = FrontieredInputHandleCore::new(&mut input, &frontiers[0]);
+                let mut input_handle = FrontieredInputHandleCore::new_tracked(&mut input, &frontiers[0], &mut previous);
                 let mut output_handle = output.activate();
                 logic(&mut input_handle, &mut output_handle);
             }
@@ -431,9 +433,11 @@ impl<G: Scope, C1: Container> Operator<G, C1> for StreamCore<G, C1> {
             // `capabilities` should be a single-element vector.
             let capability = capabilities.pop().unwrap();
             let mut logic = constructor(capability, operator_info);
+            let mut previous1 = Antichain::new();
+            let mut previous2 = Antichain::new();
             move |frontiers| {
-                let mut input1_handle = FrontieredInputHandleCore::new(&mut input1, &frontiers[0]);
-                let mut input2_handle = FrontieredInputHandleCore::new(&mut input2, &frontiers[1]);
+                let mut input1_handle = FrontieredInputHandleCore::new_tracked(&mut input1, &frontiers[0], &mut previous1);
+                let mut input2_handle = FrontieredInputHandleCore::new_tracked(&mut input2, &frontiers[1], &mut previous2);
                 let mut output_handle = output.activate();
                 logic(&mut input1_handle, &mut input2_handle, &mut output_handle);
             }
@@ -510,8 +514,9 @@ impl<G: Scope, C1: Container> Operator<G, C1> for StreamCore<G, C1> {
         let mut input = builder.new_input(self, pact);
 
         builder.build(|_capabilities| {
+            let mut previous = Antichain::new();
             move |frontiers| {
-                let mut input_handle = FrontieredInputHandleCore::new(&mut input, &frontiers[0]);
+                let mut input_handle = FrontieredInputHandleCore::new_tracked(&mut input, &frontiers[0], &mut previous);
                 logic(&mut input_handle);
             }
         });
@@ -23,7 +23,7 @@ pub use self::to_stream::ToStream;
 
 
 pub use self::generic::Operator;
-pub use self::generic::{Notificator, FrontierNotificator};
+pub use self::generic::{Notificator, FrontierNotificator, StashingNotificator};
 
 pub use self::reclock::Reclock;
 pub use self::count::Accumulate;
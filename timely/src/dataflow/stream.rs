@@ -6,11 +6,15 @@
 
 use crate::progress::{Source, Target};
 
-use crate::communication::Push;
+use crate::communication::{Push, Pull};
 use crate::dataflow::Scope;
-use crate::dataflow::channels::pushers::tee::TeeHelper;
+use crate::dataflow::channels::pushers::tee::{TeeHelper, RemovablePusher};
+use crate::dataflow::channels::pushers::{Bounded as BoundedPusher, InFlight, Timer};
+use crate::dataflow::channels::pullers::Bounded as BoundedPuller;
 use crate::dataflow::channels::Bundle;
+use std::any::Any;
 use std::fmt::{self, Debug};
+use std::rc::Rc;
 use crate::Container;
 
 // use dataflow::scopes::root::loggers::CHANNELS_Q;
@@ -27,6 +31,10 @@ pub struct StreamCore<S: Scope, C> {
     scope: S,
     /// Maintains a list of Push<Bundle<T, C>> interested in the stream's output.
     ports: TeeHelper<S::Timestamp, C>,
+    /// A human-readable label for diagnostics, empty by default.
+    label: String,
+    /// Construction-time metadata describing the stream, if any.
+    metadata: Option<Rc<dyn Any>>,
 }
 
 /// A stream batching data in vectors.
@@ -38,6 +46,25 @@ impl<S: Scope, C: Container> StreamCore<S, C> {
     /// The destination is described both by a `Target`, for progress tracking information, and a `P: Push` where the
     /// records should actually be sent. The identifier is unique to the edge and is used only for logging purposes.
     pub fn connect_to<P: Push<Bundle<S::Timestamp, C>>+'static>(&self, target: Target, pusher: P, identifier: usize) {
+        let pusher = self.connect_edge(target, pusher, identifier);
+        self.ports.add_pusher(pusher);
+    }
+    /// Connects the stream to a destination, returning a handle that disconnects the pusher when dropped.
+    ///
+    /// This behaves as `connect_to`, except that `pusher` stops receiving data once the returned
+    /// handle is dropped or its `remove` method is called. The pusher is flushed as it is removed.
+    ///
+    /// Removing the pusher does not remove the edge from progress tracking. Progress tracking continues
+    /// to account each message the source produces as in flight to `target`, and messages sent after
+    /// the removal never arrive to be consumed, which holds back the frontier at `target` indefinitely.
+    /// The pusher should only be removed once the source will produce no further data, or once the
+    /// progress of `target` and everything downstream of it is no longer of interest.
+    pub fn connect_to_removable<P: Push<Bundle<S::Timestamp, C>>+'static>(&self, target: Target, pusher: P, identifier: usize) -> RemovablePusher<S::Timestamp, C> {
+        let pusher = self.connect_edge(target, pusher, identifier);
+        self.ports.add_removable_pusher(pusher)
+    }
+    /// Logs and records the edge to `target`, and wraps `pusher` to time its pushes.
+    fn connect_edge<P: Push<Bundle<S::Timestamp, C>>+'static>(&self, target: Target, pusher: P, identifier: usize) -> Timer<S::Timestamp, C, P> {
 
         let mut logging = self.scope().logging();
         logging.as_mut().map(|l| l.log(crate::logging::ChannelsEvent {
@@ -45,15 +72,117 @@ impl<S: Scope, C: Container> StreamCore<S, C> {
             scope_addr: self.scope.addr(),
             source: (self.name.node, self.name.port),
             target: (target.node, target.port),
+            label: self.label.clone(),
         }));
 
         self.scope.add_edge(self.name, target);
-        self.ports.add_pusher(pusher);
+        Timer::new(pusher, identifier, logging)
+    }
+    /// Connects the stream to a destination, tracking the number of messages in flight.
+    ///
+    /// This behaves as `connect_to`, but wraps `pusher` and its paired `puller` so that each
+    /// message pushed increments a shared count, and each message pulled decrements it. The
+    /// returned `InFlight` handle can be polled by a source operator to throttle itself once
+    /// `bound` messages are in flight, and the returned puller should be used in place of `puller`.
+    ///
+    /// The count is maintained on the local worker only, and is exact when `pusher` and `puller`
+    /// are on the same worker. It does not affect progress tracking.
+    pub fn connect_to_bounded<P, Q>(&self, target: Target, pusher: P, puller: Q, identifier: usize, bound: usize) -> (InFlight, BoundedPuller<S::Timestamp, C, Q>)
+    where
+        P: Push<Bundle<S::Timestamp, C>>+'static,
+        Q: Pull<Bundle<S::Timestamp, C>>,
+    {
+        let in_flight = InFlight::new(bound);
+        self.connect_to(target, BoundedPusher::new(pusher, in_flight.clone()), identifier);
+        (in_flight.clone(), BoundedPuller::new(puller, in_flight))
+    }
+    /// Produces `n` streams, each of which carries all of the data of this stream.
+    ///
+    /// Each operator that consumes one of the returned streams registers its own pusher with
+    /// the shared `TeeHelper`, and is presented with every container the source produces. No
+    /// additional operator is introduced, so forking is free until the streams are consumed.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    ///
+    /// timely::example(|scope| {
+    ///     let streams = (0..10).to_stream(scope).fork(3);
+    ///     for (index, stream) in streams.iter().enumerate() {
+    ///         stream.inspect(move |x| println!("seen {}: {:?}", index, x));
+    ///     }
+    /// });
+    /// ```
+    pub fn fork(&self, n: usize) -> Vec<StreamCore<S, C>> {
+        (0 .. n).map(|_| self.clone()).collect()
     }
     /// Allocates a `Stream` from a supplied `Source` name and rendezvous point.
     pub fn new(source: Source, output: TeeHelper<S::Timestamp, C>, scope: S) -> Self {
-        Self { name: source, ports: output, scope }
+        Self { name: source, ports: output, scope, label: String::new(), metadata: None }
     }
+    /// Attaches a human-readable label to the stream, reported when it is connected.
+    ///
+    /// The label appears in the `ChannelsEvent` logged for each channel from the stream.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    ///
+    /// timely::example(|scope| {
+    ///     (0..10).to_stream(scope)
+    ///            .with_label("numbers")
+    ///            .inspect(|x| println!("seen: {:?}", x));
+    /// });
+    /// ```
+    pub fn with_label(mut self, label: &str) -> Self {
+        self.label = label.to_owned();
+        self
+    }
+    /// The label of the stream, empty if none was provided.
+    pub fn label(&self) -> &str { &self.label }
+    /// Attaches metadata to the stream, replacing any existing metadata.
+    ///
+    /// Metadata describe the stream at construction time, for example its schema, and are not
+    /// consulted by timely itself. They are shared by clones of the stream, but are not carried
+    /// through operators to the streams they produce.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::ToStream;
+    ///
+    /// timely::example(|scope| {
+    ///     let mut stream = (0..10).to_stream(scope);
+    ///     stream.set_metadata("u64 values");
+    ///     assert_eq!(stream.clone().metadata::<&str>(), Some(&"u64 values"));
+    ///     assert_eq!(stream.metadata::<String>(), None);
+    /// });
+    /// ```
+    pub fn set_metadata<M: Any>(&mut self, metadata: M) {
+        self.metadata = Some(Rc::new(metadata));
+    }
+    /// The metadata of the stream, if present and of type `M`.
+    pub fn metadata<M: Any>(&self) -> Option<&M> {
+        self.metadata.as_ref().and_then(|metadata| metadata.downcast_ref::<M>())
+    }
+    /// The number of consumers currently connected to the stream.
+    ///
+    /// Consumers are connected as the dataflow is constructed, and so the result only reflects
+    /// operators constructed so far; it is final once the dataflow has been built. An operator
+    /// can consult the fanout of its output stream, for example to skip producing an optional
+    /// output that no other operator consumes.
+    ///
+    /// # Examples
+    /// ```
+    /// use timely::dataflow::operators::{ToStream, Inspect};
+    ///
+    /// timely::example(|scope| {
+    ///     let stream = (0..10).to_stream(scope);
+    ///     assert_eq!(stream.fanout(), 0);
+    ///     stream.inspect(|x| println!("seen: {:?}", x));
+    ///     assert_eq!(stream.fanout(), 1);
+    /// });
+    /// ```
+    pub fn fanout(&self) -> usize { self.ports.pusher_count() }
     /// The name of the stream's source operator.
     pub fn name(&self) -> &Source { &self.name }
     /// The scope immediately containing the stream.
@@ -80,6 +209,8 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Stream")
             .field("source", &self.name)
+            .field("label", &self.label)
+            .field("metadata", &self.metadata.is_some())
             // TODO: Use `.finish_non_exhaustive()` after rust/#67364 lands
             .finish()
     }
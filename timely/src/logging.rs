@@ -8,6 +8,8 @@ pub type Logger<Event> = crate::logging_core::Logger<Event, WorkerIdentifier>;
 pub type TimelyLogger = Logger<TimelyEvent>;
 /// Logger for timely dataflow progress events (the "timely/progress" log stream).
 pub type TimelyProgressLogger = Logger<TimelyProgressEvent>;
+/// Logger for capability events (the "timely/capabilities" log stream).
+pub type TimelyCapabilityLogger = Logger<CapabilityEvent>;
 
 use std::time::Duration;
 use crate::dataflow::operators::capture::{Event, EventPusher};
@@ -70,6 +72,8 @@ pub struct ChannelsEvent {
     pub source: (usize, usize),
     /// Target descriptor, indicating operator index and input port.
     pub target: (usize, usize),
+    /// The label of the source stream, empty if none was provided.
+    pub label: String,
 }
 
 /// Encapsulates Any and Debug for dynamically typed timestamps in logs
@@ -203,6 +207,70 @@ pub struct ShutdownEvent {
     pub id: usize,
 }
 
+#[derive(Serialize, Deserialize, Abomonation, Debug, Clone, Hash, PartialEq, Eq, Ord, PartialOrd)]
+/// The action taken on a capability.
+pub enum CapabilityAction {
+    /// A capability was created, for example by `delayed` or `retain`.
+    Create,
+    /// A capability was downgraded to a later time.
+    Downgrade,
+    /// A capability was dropped.
+    Drop,
+}
+
+#[derive(Debug)]
+/// The creation, downgrade, or release of an operator's capability.
+///
+/// These events are logged to the `"timely/capabilities"` logger, if one is registered.
+pub struct CapabilityEvent {
+    /// Worker-unique identifier for the operator, linkable to the identifiers in `OperatesEvent`.
+    pub operator: usize,
+    /// The time of the capability, after the action.
+    pub time: Box<dyn ProgressEventTimestamp>,
+    /// The action taken on the capability.
+    pub action: CapabilityAction,
+}
+
+#[derive(Serialize, Deserialize, Abomonation, Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+/// Time spent pushing messages to the recipients of a channel, since its previous flush.
+pub struct PushTimingEvent {
+    /// Channel identifier, linkable to the identifiers in `ChannelsEvent`.
+    pub channel: usize,
+    /// Number of messages pushed.
+    pub messages: usize,
+    /// Wall-clock time spent in the recipients' `push` calls, including the flush.
+    pub elapsed: Duration,
+}
+
+#[derive(Serialize, Deserialize, Abomonation, Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+/// Messages observed on a channel by a `log_rates` operator during a window of time.
+pub struct MessageRateEvent {
+    /// Worker-unique identifier of the channel, linkable to the identifiers in `ChannelsEvent`.
+    pub channel_id: usize,
+    /// Number of messages received.
+    pub count: usize,
+    /// The length of the window.
+    pub window: Duration,
+}
+
+/// A logger of frontier changes at operator inputs, for timestamps of type `T`.
+pub type FrontierLogger<T> = Logger<FrontierUpdateEvent<T>>;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+/// Changes to the frontier of an operator input, as applied by the operator.
+///
+/// These events are logged to the `"timely/frontier"` logger, if one is registered for the
+/// timestamp type of the operator's scope. Applying the changes in order to an empty
+/// `MutableAntichain` reconstructs the frontier of the input as the operator saw it.
+pub struct FrontierUpdateEvent<T> {
+    /// Worker-unique identifier of the operator, linkable to the identifiers in `OperatesEvent`.
+    pub operator_id: usize,
+    /// The input port of the operator.
+    pub port: usize,
+    /// Changes to the counts of times in the input's frontier.
+    pub changes: Vec<(T, i64)>,
+}
+
 #[derive(Serialize, Deserialize, Abomonation, Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 /// Application-defined code start or stop
 pub struct ApplicationEvent {
@@ -215,6 +283,8 @@ pub struct ApplicationEvent {
 #[derive(Serialize, Deserialize, Abomonation, Debug, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
 /// Application-defined code start or stop
 pub struct GuardedMessageEvent {
+    /// Worker-unique identifier for the operator, linkable to the identifiers in `OperatesEvent`.
+    pub operator: usize,
     /// True when activity begins, false when it stops
     pub is_start: bool,
 }
@@ -301,6 +371,10 @@ pub enum TimelyEvent {
     Input(InputEvent),
     /// Park event.
     Park(ParkEvent),
+    /// Push timing event.
+    PushTiming(PushTimingEvent),
+    /// Message rate event.
+    MessageRate(MessageRateEvent),
     /// Unstructured event.
     Text(String),
 }
@@ -352,3 +426,12 @@ impl From<InputEvent> for TimelyEvent {
 impl From<ParkEvent> for TimelyEvent {
     fn from(v: ParkEvent) -> TimelyEvent { TimelyEvent::Park(v) }
 }
+
+impl From<PushTimingEvent> for TimelyEvent {
+    fn from(v: PushTimingEvent) -> TimelyEvent { TimelyEvent::PushTiming(v) }
+}
+
+
+impl From<MessageRateEvent> for TimelyEvent {
+    fn from(v: MessageRateEvent) -> TimelyEvent { TimelyEvent::MessageRate(v) }
+}
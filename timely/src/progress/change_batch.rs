@@ -178,6 +178,38 @@ where
         self.updates.iter()
     }
 
+    /// Iterates over the compacted contents of the map, without requiring mutable access.
+    ///
+    /// Unlike `iter`, this does not compact the representation in place, and so can be used for
+    /// read-only inspection, for example in logging or assertions. If the batch is not known to be
+    /// compact its updates are consolidated into a new list, and so this may allocate.
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use timely::progress::ChangeBatch;
+    ///
+    /// let mut batch = ChangeBatch::<usize>::new_from(17, 1);
+    /// batch.update(18, 1);
+    /// batch.update(17, -1);
+    /// assert_eq!(batch.iter_ref().collect::<Vec<_>>(), vec![(&18, 1)]);
+    /// assert!(batch.is_dirty());
+    ///```
+    pub fn iter_ref(&self) -> ::std::vec::IntoIter<(&T, i64)> {
+        let mut updates = self.updates.iter().map(|(time, diff)| (time, *diff)).collect::<Vec<_>>();
+        if self.is_dirty() && updates.len() > 1 {
+            updates.sort_by(|x,y| x.0.cmp(y.0));
+            for i in 0 .. updates.len() - 1 {
+                if updates[i].0 == updates[i+1].0 {
+                    updates[i+1].1 += updates[i].1;
+                    updates[i].1 = 0;
+                }
+            }
+            updates.retain(|x| x.1 != 0);
+        }
+        updates.into_iter()
+    }
+
     /// Drains the set of updates.
     ///
     /// This operation first compacts the set of updates so that the drained results
@@ -311,6 +343,30 @@ where
     }
 }
 
+impl<T: Ord> ::std::iter::FromIterator<(T, i64)> for ChangeBatch<T> {
+    /// Collects updates into a `ChangeBatch`, compacting once after all updates are collected.
+    ///
+    /// The resulting batch is compact, with at most one update for each key and no zero updates.
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use timely::progress::ChangeBatch;
+    ///
+    /// let mut batch = vec![(17, 1), (5, 2), (17, -1)].into_iter().collect::<ChangeBatch<usize>>();
+    /// assert_eq!(batch.len(), 1);
+    /// assert_eq!(batch.into_inner(), vec![(5, 2)]);
+    ///```
+    fn from_iter<I: IntoIterator<Item=(T, i64)>>(iterator: I) -> Self {
+        let mut result = ChangeBatch {
+            updates: iterator.into_iter().collect(),
+            clean: 0,
+        };
+        result.compact();
+        result
+    }
+}
+
 impl<T> Default for ChangeBatch<T> {
     fn default() -> Self {
         Self::new()
@@ -503,6 +503,33 @@ impl<T> MutableAntichain<T> {
         self.frontier().less_equal(time)
     }
 
+    /// Returns the elements of `previous` that are complete with respect to the current frontier.
+    ///
+    /// An element of a previous frontier is complete once no element of the current frontier
+    /// is less or equal to it. If the current frontier is empty, all elements are complete.
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use timely::progress::frontier::{Antichain, MutableAntichain};
+    ///
+    /// let mut frontier = MutableAntichain::new_bottom(1u64);
+    /// let previous = frontier.frontier().to_owned();
+    /// assert!(frontier.completed_since(previous.borrow()).is_empty());
+    ///
+    /// frontier.update_iter(vec![(1, -1), (2, 1)]);
+    /// assert_eq!(frontier.completed_since(previous.borrow()), Antichain::from_elem(1));
+    ///
+    /// frontier.update_iter(vec![(2, -1)]);
+    /// assert_eq!(frontier.completed_since(previous.borrow()), Antichain::from_elem(1));
+    ///```
+    pub fn completed_since(&self, previous: AntichainRef<T>) -> Antichain<T>
+    where
+        T: Clone + PartialOrder,
+    {
+        previous.iter().filter(|time| !self.less_equal(*time)).cloned().collect()
+    }
+
     /// Applies updates to the antichain and enumerates any changes.
     ///
     /// # Examples